@@ -0,0 +1,25 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use std::str::FromStr;
+use steamidfx::id::{Id, Id64};
+
+const IDS: [(&str, &str); 3] = [
+    ("id64", "76561197983318796"),
+    ("id32", "STEAM_0:0:11526534"),
+    ("id3", "[U:1:23053068]"),
+];
+
+fn parse(c: &mut Criterion) {
+    for (format, value) in &IDS {
+        let mut group = c.benchmark_group(*format);
+        group.bench_function("Id::from_str + id64", |b| {
+            b.iter(|| Id::from_str(black_box(value)).unwrap().id64().unwrap())
+        });
+        group.bench_function("Id64::from_str", |b| {
+            b.iter(|| Id64::from_str(black_box(value)).unwrap())
+        });
+        group.finish();
+    }
+}
+
+criterion_group!(benches, parse);
+criterion_main!(benches);
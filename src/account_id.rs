@@ -0,0 +1,264 @@
+//! The 32-bit account ids.
+//!
+//! The account id is the lower half of the steam id 64: the account number and
+//! the authentication server bit (`account * 2 + authentication_server`). It is
+//! the last number of the steam id 3, and it is what Dota 2, `OpenDota`, the
+//! trade offer links and the friend codes use instead of the steam id.
+//!
+//! Example:
+//!
+//! ```rust
+//! use std::convert::TryFrom;
+//! use steamidfx::account_id::AccountId;
+//! use steamidfx::id::{Id3, Id32, Id64};
+//!
+//! let account_id = AccountId::try_from(Id32("STEAM_0:0:11526534".to_owned())).unwrap();
+//! assert_eq!(account_id, AccountId(23053068));
+//! assert_eq!(Id64::from(account_id), Id64(76561197983318796));
+//! assert_eq!(Id3::try_from(account_id).unwrap(), Id3("[U:1:23053068]".to_owned()));
+//!
+//! let next: Vec<_> = AccountId::range(account_id..).take(2).collect();
+//! assert_eq!(next, vec![AccountId(23053068), AccountId(23053069)]);
+//! ```
+use crate::error::Error;
+use crate::id::{AccountType, Id, Id3, Id32, Id64, Info, Universe, MAX_ACCOUNT};
+use crate::validate::Field;
+use core::convert::TryFrom;
+use core::ops::{Bound, RangeBounds};
+
+/// The account id: the account number and the authentication server bit.
+///
+/// Only the individual accounts of the desktop instance in the public universe convert to the
+/// account id, as that is the steam id it converts back to.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Copy, Clone, Default, Ord, PartialOrd, Hash, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct AccountId(pub u32);
+
+impl AccountId {
+    /// Creates the account id from the account number and the authentication server bit.
+    /// Returns `None` if the account number doesn't fit 31 bits or the bit isn't `0` or `1`.
+    #[must_use]
+    pub const fn new(account: u32, authentication_server: u8) -> Option<AccountId> {
+        if account > MAX_ACCOUNT || authentication_server > 1 {
+            return None;
+        }
+        Some(AccountId(account << 1 | authentication_server as u32))
+    }
+
+    /// Returns the account number.
+    #[must_use]
+    pub const fn account(self) -> u32 {
+        self.0 >> 1
+    }
+
+    /// Returns the authentication server bit.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn authentication_server(self) -> u8 {
+        (self.0 & 1) as u8
+    }
+
+    /// Returns the next account id, or `None` if this is the last one.
+    #[must_use]
+    pub const fn checked_next(self) -> Option<AccountId> {
+        match self.0.checked_add(1) {
+            Some(next) => Some(AccountId(next)),
+            None => None,
+        }
+    }
+
+    /// Returns the previous account id, or `None` if this is the first one.
+    #[must_use]
+    pub const fn checked_prev(self) -> Option<AccountId> {
+        match self.0.checked_sub(1) {
+            Some(prev) => Some(AccountId(prev)),
+            None => None,
+        }
+    }
+
+    /// Returns the steam id 64 of the individual account with this account id in the universe
+    /// passed, with the default instance of the individual accounts.
+    #[must_use]
+    pub const fn id64(self, universe: Universe) -> Id64 {
+        Id64::from_parts(
+            universe,
+            AccountType::Individual,
+            AccountType::Individual.default_instance(),
+            self,
+        )
+    }
+
+    /// Returns an iterator over all the account ids within the range passed, in both directions.
+    pub fn range(range: impl RangeBounds<AccountId>) -> impl DoubleEndedIterator<Item = AccountId> {
+        let start = match range.start_bound() {
+            Bound::Included(start) => Some(start.0),
+            Bound::Excluded(start) => start.0.checked_add(1),
+            Bound::Unbounded => Some(u32::MIN),
+        };
+        let end = match range.end_bound() {
+            Bound::Included(end) => Some(end.0),
+            Bound::Excluded(end) => end.0.checked_sub(1),
+            Bound::Unbounded => Some(u32::MAX),
+        };
+        start
+            .zip(end)
+            .into_iter()
+            .flat_map(|(start, end)| start..=end)
+            .map(AccountId)
+    }
+}
+
+impl From<u32> for AccountId {
+    fn from(account_id: u32) -> Self {
+        AccountId(account_id)
+    }
+}
+
+impl From<AccountId> for u32 {
+    fn from(account_id: AccountId) -> Self {
+        account_id.0
+    }
+}
+
+impl core::fmt::Display for AccountId {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&self.0, fmt)
+    }
+}
+
+impl Info {
+    /// Returns the account id: the account number and the authentication server bit.
+    #[must_use]
+    pub fn account_id(&self) -> AccountId {
+        AccountId(self.account << 1 | u32::from(self.authentication_server & 1))
+    }
+}
+
+/// Returns the account id of the steam id, see [`AccountId`] for which ids convert.
+pub(crate) fn individual_account_id(
+    info: &Info,
+    target: &'static str,
+) -> crate::error::Result<AccountId> {
+    if info.account_type != AccountType::Individual {
+        return Err(Error::unsupported_account_type(info.account_type, target));
+    }
+    if info.universe != Universe::Public {
+        return Err(Error::UnsupportedUniverse {
+            universe: info.universe,
+            target,
+        });
+    }
+    if info.instance != AccountType::Individual.default_instance() {
+        return Err(Error::OutOfRange {
+            field: Field::Instance,
+            value: u64::from(info.instance),
+        });
+    }
+    Ok(info.account_id())
+}
+
+/// Converts the account id back to the steam id 64, see [`AccountId`].
+impl From<AccountId> for Id64 {
+    fn from(account_id: AccountId) -> Self {
+        account_id.id64(Universe::Public)
+    }
+}
+
+impl TryFrom<AccountId> for Id32 {
+    type Error = crate::error::Error;
+
+    fn try_from(account_id: AccountId) -> crate::error::Result<Id32> {
+        Id32::try_from(Id64::from(account_id))
+    }
+}
+
+impl TryFrom<AccountId> for Id3 {
+    type Error = crate::error::Error;
+
+    fn try_from(account_id: AccountId) -> crate::error::Result<Id3> {
+        Id3::try_from(Id64::from(account_id))
+    }
+}
+
+impl TryFrom<Id64> for AccountId {
+    type Error = crate::error::Error;
+
+    fn try_from(id: Id64) -> crate::error::Result<AccountId> {
+        individual_account_id(&id.info()?, "an account id")
+    }
+}
+
+impl TryFrom<Id32> for AccountId {
+    type Error = crate::error::Error;
+
+    fn try_from(id: Id32) -> crate::error::Result<AccountId> {
+        individual_account_id(&id.info()?, "an account id")
+    }
+}
+
+impl TryFrom<Id3> for AccountId {
+    type Error = crate::error::Error;
+
+    fn try_from(id: Id3) -> crate::error::Result<AccountId> {
+        individual_account_id(&id.info()?, "an account id")
+    }
+}
+
+impl TryFrom<Id> for AccountId {
+    type Error = crate::error::Error;
+
+    fn try_from(id: Id) -> crate::error::Result<AccountId> {
+        individual_account_id(&id.info()?, "an account id")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::unreadable_literal)]
+    #[allow(clippy::too_many_lines)]
+    #[test]
+    fn account_id_conversions() {
+        for id in &[76561197960287930u64, 76561197983318796, 76561202255233023] {
+            let id64 = Id64(*id);
+            let account_id = AccountId::try_from(id64).unwrap();
+            assert_eq!(Id64::from(account_id), id64);
+            let id32 = Id32::try_from(account_id).unwrap();
+            assert_eq!(AccountId::try_from(id32).unwrap(), account_id);
+            let id3 = Id3::try_from(account_id).unwrap();
+            assert_eq!(AccountId::try_from(id3).unwrap(), account_id);
+        }
+        assert!(AccountId::try_from(Id64(103582791429521412)).is_err());
+        let beta = Id64(76561197983318796)
+            .with_universe(Universe::Beta)
+            .unwrap();
+        assert_eq!(beta, Id64(148618792021246732));
+        assert!(AccountId::try_from(beta).is_err());
+        let console = Id64(76561197983318796).with_instance(1025).unwrap();
+        assert!(AccountId::try_from(console).is_err());
+        assert!(AccountId::try_from(Id3("[U:1:23053068:4]".to_owned())).is_err());
+        assert_eq!(AccountId::new(11526534, 1), Some(AccountId(23053069)));
+        assert_eq!(AccountId::new(MAX_ACCOUNT + 1, 0), None);
+        assert_eq!(AccountId::new(1, 2), None);
+    }
+
+    #[test]
+    fn account_id_arithmetic() {
+        let last = AccountId(u32::MAX);
+        assert_eq!(last.checked_next(), None);
+        assert_eq!(last.checked_prev(), Some(AccountId(u32::MAX - 1)));
+        assert_eq!(AccountId(0).checked_prev(), None);
+        assert_eq!(last.account(), MAX_ACCOUNT);
+        assert_eq!(last.authentication_server(), 1);
+        let range: Vec<_> = AccountId::range(AccountId(3)..AccountId(6)).rev().collect();
+        assert_eq!(range, vec![AccountId(5), AccountId(4), AccountId(3)]);
+        assert_eq!(AccountId::range(last..).count(), 1);
+        assert_eq!(AccountId::range(..AccountId(0)).count(), 0);
+        assert_eq!(AccountId::range(AccountId(2)..=AccountId(2)).count(), 1);
+    }
+}
@@ -52,7 +52,7 @@ impl BitIterator {
         // The code is written in a generic style, hence uses the `size_of`.
         // In fact, we always know how many bytes it is in an `u64` primitive.
         // As we know that this value can't be higher than `8`, we silence the clippy warning.
-        let current_pos = std::mem::size_of::<u64>() as u8 * 8;
+        let current_pos = core::mem::size_of::<u64>() as u8 * 8;
         assert!(iter_length <= current_pos);
         BitIterator {
             object,
@@ -74,7 +74,7 @@ impl BitIterator {
 
     /// Changes the iteration length to `amount` and tries to fetch the `amount` of bits
     /// from the `object`.
-    pub fn next_bits<T: std::convert::TryFrom<u64>>(&mut self, amount: u8) -> Option<T> {
+    pub fn next_bits<T: core::convert::TryFrom<u64>>(&mut self, amount: u8) -> Option<T> {
         self.change_iter_length(amount);
         self.next().and_then(|next| T::try_from(next).ok())
     }
@@ -0,0 +1,267 @@
+//! Building steam ids 64 field by field.
+//!
+//! The builder starts with an individual account in the public universe and
+//! picks the instance the account type implies unless it is set explicitly. The
+//! `with_*` methods change a single field of an existing id:
+//!
+//! ```rust
+//! use std::convert::TryFrom;
+//! use steamidfx::id::{AccountType, Id64, Universe};
+//!
+//! let id = Id64::builder().account(11526534).build().unwrap();
+//! assert_eq!(id, Id64(76561197983318796));
+//! assert_eq!(id.with_authentication_server(1).unwrap(), Id64(76561197983318797));
+//!
+//! let clan = Id64::builder()
+//!     .account_type(AccountType::Clan)
+//!     .account(2)
+//!     .build()
+//!     .unwrap();
+//! assert_eq!(clan, Id64(103582791429521412));
+//! assert_eq!(clan.with_universe(Universe::Beta).unwrap().info().unwrap().universe, Universe::Beta);
+//! assert_eq!(Id64::try_from(clan.info().unwrap()).unwrap(), clan);
+//! ```
+use crate::account_id::AccountId;
+use crate::id::{AccountType, Id64, Info, Universe};
+use core::convert::TryFrom;
+
+/// Collects the fields of a steam id 64 and checks they fit it when the id is built.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Id64Builder {
+    universe: Universe,
+    account_type: AccountType,
+    instance: Option<u32>,
+    account: u32,
+    authentication_server: u8,
+}
+
+impl Id64Builder {
+    /// Sets the universe. The default is the public one.
+    #[must_use]
+    pub const fn universe(self, universe: Universe) -> Self {
+        Id64Builder { universe, ..self }
+    }
+
+    /// Sets the account type. The default is the individual one.
+    #[must_use]
+    pub const fn account_type(self, account_type: AccountType) -> Self {
+        Id64Builder {
+            account_type,
+            ..self
+        }
+    }
+
+    /// Sets the instance. The default is the one the account type implies.
+    #[must_use]
+    pub const fn instance(self, instance: u32) -> Self {
+        Id64Builder {
+            instance: Some(instance),
+            ..self
+        }
+    }
+
+    /// Sets the account number. The default is `0`.
+    #[must_use]
+    pub const fn account(self, account: u32) -> Self {
+        Id64Builder { account, ..self }
+    }
+
+    /// Sets the authentication server bit. The default is `0`.
+    #[must_use]
+    pub const fn authentication_server(self, authentication_server: u8) -> Self {
+        Id64Builder {
+            authentication_server,
+            ..self
+        }
+    }
+
+    /// Sets both the account number and the authentication server bit.
+    #[must_use]
+    pub const fn account_id(self, account_id: AccountId) -> Self {
+        self.account(account_id.account())
+            .authentication_server(account_id.authentication_server())
+    }
+
+    /// Builds the steam id.
+    ///
+    /// # Errors
+    /// Returns an error if a value doesn't fit its field of the steam id 64.
+    pub fn build(self) -> crate::error::Result<Id64> {
+        let instance = match self.instance {
+            Some(instance) => instance,
+            None => self.account_type.default_instance(),
+        };
+        Id64::new_full(
+            self.universe,
+            self.account_type,
+            instance,
+            self.authentication_server,
+            self.account,
+        )
+    }
+}
+
+impl Default for Id64Builder {
+    fn default() -> Self {
+        Id64::builder()
+    }
+}
+
+impl Id64 {
+    /// Returns the builder of the steam id of an individual account in the public universe.
+    #[must_use]
+    pub const fn builder() -> Id64Builder {
+        Id64Builder {
+            universe: Universe::Public,
+            account_type: AccountType::Individual,
+            instance: None,
+            account: 0,
+            authentication_server: 0,
+        }
+    }
+
+    /// Returns the id with the universe changed.
+    ///
+    /// # Errors
+    /// Returns an error if the id is incorrect.
+    pub fn with_universe(self, universe: Universe) -> crate::error::Result<Id64> {
+        self.with(|info| info.universe = universe)
+    }
+
+    /// Returns the id with the account type changed. The instance stays the same.
+    ///
+    /// # Errors
+    /// Returns an error if the id is incorrect.
+    pub fn with_account_type(self, account_type: AccountType) -> crate::error::Result<Id64> {
+        self.with(|info| info.account_type = account_type)
+    }
+
+    /// Returns the id with the instance changed.
+    ///
+    /// # Errors
+    /// Returns an error if the id is incorrect or the instance doesn't fit 20 bits.
+    pub fn with_instance(self, instance: u32) -> crate::error::Result<Id64> {
+        self.with(|info| info.instance = instance)
+    }
+
+    /// Returns the id with the account number changed.
+    ///
+    /// # Errors
+    /// Returns an error if the id is incorrect or the account number doesn't fit 31 bits.
+    pub fn with_account(self, account: u32) -> crate::error::Result<Id64> {
+        self.with(|info| info.account = account)
+    }
+
+    /// Returns the id with the authentication server bit changed.
+    ///
+    /// # Errors
+    /// Returns an error if the id is incorrect or the bit isn't `0` or `1`.
+    pub fn with_authentication_server(
+        self,
+        authentication_server: u8,
+    ) -> crate::error::Result<Id64> {
+        self.with(|info| info.authentication_server = authentication_server)
+    }
+
+    /// Returns the id with the account number and the authentication server bit changed.
+    ///
+    /// # Errors
+    /// Returns an error if the id is incorrect.
+    pub fn with_account_id(self, account_id: AccountId) -> crate::error::Result<Id64> {
+        self.with(|info| {
+            info.account = account_id.account();
+            info.authentication_server = account_id.authentication_server();
+        })
+    }
+
+    /// Returns the id with the information about the account changed.
+    fn with(self, change: impl FnOnce(&mut Info)) -> crate::error::Result<Id64> {
+        let mut info = self.info()?;
+        change(&mut info);
+        Id64::try_from(info)
+    }
+}
+
+impl TryFrom<Info> for Id64 {
+    type Error = crate::error::Error;
+
+    fn try_from(info: Info) -> crate::error::Result<Id64> {
+        Id64::new_full(
+            info.universe,
+            info.account_type,
+            info.instance,
+            info.authentication_server,
+            info.account,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Error;
+    use crate::id::{MAX_ACCOUNT, MAX_INSTANCE};
+    use crate::validate::Field;
+
+    #[allow(clippy::unreadable_literal)]
+    #[allow(clippy::too_many_lines)]
+    #[test]
+    fn builder_defaults() {
+        assert_eq!(
+            Id64Builder::default().build().unwrap(),
+            Id64(76561197960265728)
+        );
+        let lobby = Id64::builder()
+            .account_type(AccountType::Chat)
+            .instance(crate::id::ChatInstanceFlags::LOBBY.0)
+            .account_id(AccountId(4))
+            .build()
+            .unwrap();
+        assert_eq!(lobby, Id64(109212290963734532));
+        let info = lobby.info().unwrap();
+        assert_eq!(Id64::try_from(info).unwrap(), lobby);
+        let game_server = Id64::builder().account_type(AccountType::GameServer);
+        assert_eq!(game_server.build().unwrap().instance(), 0);
+        assert_eq!(
+            Id64::builder().instance(MAX_INSTANCE + 1).build(),
+            Err(Error::OutOfRange {
+                field: Field::Instance,
+                value: u64::from(MAX_INSTANCE) + 1
+            })
+        );
+    }
+
+    #[allow(clippy::unreadable_literal)]
+    #[allow(clippy::too_many_lines)]
+    #[test]
+    fn with_changes_a_single_field() {
+        let id = Id64(76561197983318796);
+        let info = id.info().unwrap();
+        let changed = id.with_instance(4).unwrap().info().unwrap();
+        assert_eq!(
+            changed,
+            Info {
+                instance: 4,
+                ..info
+            }
+        );
+        let changed = id.with_account_type(AccountType::Multiseat).unwrap();
+        assert_eq!(changed.account_type(), AccountType::Multiseat);
+        assert_eq!(id.with_account(1).unwrap().account(), 1);
+        assert_eq!(
+            id.with_account_id(AccountId(3)).unwrap().account_id(),
+            AccountId(3)
+        );
+        assert!(id.with_account(MAX_ACCOUNT + 1).is_err());
+        assert!(id.with_authentication_server(2).is_err());
+        let unknown = Id64(u64::MAX).with_account(1).unwrap();
+        assert_eq!(unknown.universe(), Universe::Unknown(0xFF));
+        assert!(id.with_universe(Universe::Unknown(1)).is_err());
+        assert!(id.with_account_type(AccountType::Unknown(7)).is_err());
+        assert!(Id64::try_from(Info {
+            universe: Universe::Unknown(2),
+            ..info
+        })
+        .is_err());
+    }
+}
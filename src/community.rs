@@ -0,0 +1,261 @@
+//! Steam Community links.
+//!
+//! Parses the links to the profiles and the groups, like
+//! `https://steamcommunity.com/profiles/76561197983318796`,
+//! `https://steamcommunity.com/id/<vanity>`, `https://steamcommunity.com/gid/<id>`,
+//! `https://steamcommunity.com/groups/<name>` and `steam://url/SteamIDPage/<id>`,
+//! and builds the canonical links from the steam ids.
+//!
+//! Example:
+//!
+//! ```rust
+//! use std::str::FromStr;
+//! use steamidfx::community::CommunityUrl;
+//! use steamidfx::id::{Id, Id64};
+//!
+//! let url = CommunityUrl::from_str("https://steamcommunity.com/profiles/76561197983318796/").unwrap();
+//! assert_eq!(url, CommunityUrl::Id(Id::Id64(Id64(76561197983318796))));
+//! assert_eq!(
+//!     url.to_url().unwrap(),
+//!     "https://steamcommunity.com/profiles/76561197983318796"
+//! );
+//!
+//! let url = CommunityUrl::from_str("steamcommunity.com/id/gabelogannewell").unwrap();
+//! assert_eq!(url, CommunityUrl::ProfileVanity("gabelogannewell".to_owned()));
+//! ```
+use crate::id::{AccountType, Id, Id64};
+use alloc::borrow::ToOwned;
+use alloc::format;
+use alloc::string::String;
+use core::str::FromStr;
+
+/// The base of all the steam community links.
+pub(crate) const COMMUNITY_URL: &str = "https://steamcommunity.com";
+
+/// A parsed steam community link.
+#[derive(Debug, Clone, Ord, PartialOrd, Hash, PartialEq, Eq)]
+pub enum CommunityUrl {
+    /// A link which contains the steam id itself: `/profiles/<id>`, `/gid/<id>` or
+    /// `steam://url/SteamIDPage/<id>`.
+    Id(Id),
+    /// A link to a profile by its custom name: `/id/<vanity>`. The steam id must be resolved
+    /// using the Steam Web API (`ResolveVanityURL`).
+    ProfileVanity(String),
+    /// A link to a group by its custom name: `/groups/<name>`. The steam id must be resolved
+    /// using the Steam Web API (`ResolveVanityURL` with `url_type=2`).
+    GroupVanity(String),
+}
+
+impl CommunityUrl {
+    /// Creates the canonical link for the steam id.
+    ///
+    /// # Errors
+    /// Returns an error if the id is incorrect or the account type doesn't have a community page.
+    pub fn new(id: Id64) -> crate::error::Result<CommunityUrl> {
+        match id.info()?.account_type {
+            AccountType::Individual | AccountType::Clan => Ok(CommunityUrl::Id(Id::Id64(id))),
+            account_type => Err(no_community_page(account_type)),
+        }
+    }
+
+    /// Returns the steam id if the link contains it, and `None` if it needs to be resolved first.
+    #[must_use]
+    pub fn id(&self) -> Option<&Id> {
+        match self {
+            CommunityUrl::Id(id) => Some(id),
+            CommunityUrl::ProfileVanity(_) | CommunityUrl::GroupVanity(_) => None,
+        }
+    }
+
+    /// Renders the canonical link: `/profiles/<id64>` for the individual accounts, `/gid/<id64>`
+    /// for the clans and `/id/<vanity>` or `/groups/<name>` for the custom names.
+    ///
+    /// # Errors
+    /// Returns an error if the id can't be converted to id64 or the account type doesn't have a
+    /// community page.
+    pub fn to_url(&self) -> crate::error::Result<String> {
+        Ok(match self {
+            CommunityUrl::Id(id) => {
+                let id = id.id64()?;
+                let kind = match id.info()?.account_type {
+                    AccountType::Individual => "profiles",
+                    AccountType::Clan => "gid",
+                    account_type => return Err(no_community_page(account_type)),
+                };
+                format!("{}/{}/{}", COMMUNITY_URL, kind, id.0)
+            }
+            CommunityUrl::ProfileVanity(vanity) => format!("{COMMUNITY_URL}/id/{vanity}"),
+            CommunityUrl::GroupVanity(name) => format!("{COMMUNITY_URL}/groups/{name}"),
+        })
+    }
+}
+
+/// Returns the error for the account types which can't be linked to.
+fn no_community_page(account_type: AccountType) -> crate::error::Error {
+    crate::error::Error::unsupported_account_type(account_type, "a community page")
+}
+
+/// Parses the steam id written in the link and makes sure it is a correct one.
+fn parse_url_id(value: &str) -> Option<CommunityUrl> {
+    let id = Id::from_str(value).ok()?;
+    id.id64().ok()?.info().ok()?;
+    Some(CommunityUrl::Id(id))
+}
+
+/// Checks the custom name consists only of the characters steam allows there.
+fn parse_vanity(value: &str) -> Option<String> {
+    if value.is_empty()
+        || !value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+    {
+        return None;
+    }
+    Some(value.to_owned())
+}
+
+/// Strips the `https://` or the `http://` scheme from the link, if there is one.
+pub(crate) fn strip_scheme(value: &str) -> &str {
+    value
+        .strip_prefix("https://")
+        .or_else(|| value.strip_prefix("http://"))
+        .unwrap_or(value)
+}
+
+/// Strips the scheme and the host of the steam community from the link, returning the path.
+pub(crate) fn strip_host(value: &str) -> Option<&str> {
+    let host = strip_scheme(value);
+    host.strip_prefix("www.")
+        .unwrap_or(host)
+        .strip_prefix("steamcommunity.com/")
+}
+
+/// Splits the path into the kind of the link and its argument, dropping the query and the
+/// fragment.
+fn split_path(path: &str) -> (&str, &str) {
+    let path = path.split(['?', '#']).next().unwrap_or(path);
+    let mut segments = path.split('/');
+    let kind = segments.next().unwrap_or_default();
+    (kind, segments.next().unwrap_or_default())
+}
+
+/// Parses the path of the link the steam client opens: `steam://url/<path>`.
+fn parse_client_path(path: &str) -> Option<CommunityUrl> {
+    match split_path(path) {
+        ("SteamIDPage" | "GroupSteamIDPage", argument) => parse_url_id(argument),
+        _ => None,
+    }
+}
+
+/// Parses the path of the steam community link.
+fn parse_web_path(path: &str) -> Option<CommunityUrl> {
+    match split_path(path) {
+        ("profiles" | "gid", argument) => parse_url_id(argument),
+        ("id", argument) => parse_vanity(argument).map(CommunityUrl::ProfileVanity),
+        ("groups", argument) => parse_vanity(argument).map(CommunityUrl::GroupVanity),
+        _ => None,
+    }
+}
+
+impl FromStr for CommunityUrl {
+    type Err = crate::error::Error;
+
+    fn from_str(value: &str) -> crate::error::Result<Self> {
+        match value.strip_prefix("steam://url/") {
+            Some(path) => parse_client_path(path),
+            None => strip_host(value).and_then(parse_web_path),
+        }
+        .ok_or_else(|| crate::error::Error::invalid_format("community link", value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::id::Id3;
+
+    #[allow(clippy::unreadable_literal)]
+    #[allow(clippy::too_many_lines)]
+    #[test]
+    fn community_url_parse() {
+        let profile = CommunityUrl::Id(Id::Id64(Id64(76561197983318796)));
+        let group = CommunityUrl::Id(Id::Id64(Id64(103582791429521412)));
+        let urls = [
+            (
+                "https://steamcommunity.com/profiles/76561197983318796",
+                &profile,
+            ),
+            (
+                "http://www.steamcommunity.com/profiles/76561197983318796/",
+                &profile,
+            ),
+            (
+                "https://steamcommunity.com/profiles/76561197983318796/inventory/#730",
+                &profile,
+            ),
+            ("steam://url/SteamIDPage/76561197983318796", &profile),
+            ("https://steamcommunity.com/gid/103582791429521412", &group),
+            ("steam://url/GroupSteamIDPage/103582791429521412", &group),
+        ];
+        for (url, expected) in &urls {
+            assert_eq!(&&CommunityUrl::from_str(url).unwrap(), expected);
+        }
+        assert_eq!(
+            CommunityUrl::from_str("https://steamcommunity.com/profiles/[U:1:23053068]").unwrap(),
+            CommunityUrl::Id(Id::Id3(Id3("[U:1:23053068]".to_owned())))
+        );
+        assert_eq!(
+            CommunityUrl::from_str("https://steamcommunity.com/groups/Valve?tab=members").unwrap(),
+            CommunityUrl::GroupVanity("Valve".to_owned())
+        );
+    }
+
+    #[test]
+    fn community_url_invalid() {
+        assert!(CommunityUrl::from_str("https://steamcommunity.com/profiles/").is_err());
+        assert!(CommunityUrl::from_str("https://steamcommunity.com/profiles/abc").is_err());
+        assert!(CommunityUrl::from_str("https://steamcommunity.com/id/").is_err());
+        assert!(CommunityUrl::from_str("https://steamcommunity.com/id/a b").is_err());
+        assert!(CommunityUrl::from_str("https://steamcommunity.com/market/").is_err());
+        assert!(CommunityUrl::from_str("https://example.com/id/gaben").is_err());
+        let profile = "steamcommunity.com/profiles/76561197983318796";
+        assert!(CommunityUrl::from_str(&format!("https://https://{profile}")).is_err());
+        assert!(CommunityUrl::from_str(&format!("https://www.www.{profile}")).is_err());
+        assert!(CommunityUrl::from_str("steam://url/profiles/76561197983318796").is_err());
+        assert!(CommunityUrl::from_str("steam://url/id/gaben").is_err());
+        assert!(
+            CommunityUrl::from_str("steamcommunity.com/SteamIDPage/76561197983318796").is_err()
+        );
+    }
+
+    #[allow(clippy::unreadable_literal)]
+    #[test]
+    fn community_url_build() {
+        assert_eq!(
+            CommunityUrl::new(Id64(103582791429521412))
+                .unwrap()
+                .to_url()
+                .unwrap(),
+            "https://steamcommunity.com/gid/103582791429521412"
+        );
+        assert_eq!(
+            CommunityUrl::GroupVanity("Valve".to_owned())
+                .to_url()
+                .unwrap(),
+            "https://steamcommunity.com/groups/Valve"
+        );
+        assert!(CommunityUrl::new(Id64(90071992547409921)).is_err());
+    }
+
+    #[allow(clippy::unreadable_literal)]
+    #[test]
+    fn community_url_clans() {
+        let clan = Id64::new_clan(crate::id::Universe::Public, 4).unwrap();
+        let url = CommunityUrl::new(clan).unwrap().to_url().unwrap();
+        assert_eq!(url, "https://steamcommunity.com/gid/103582791429521412");
+        let parsed = CommunityUrl::from_str(&url).unwrap();
+        assert_eq!(parsed.id().unwrap().id64().unwrap(), clan);
+        let parsed = CommunityUrl::from_str("https://steamcommunity.com/gid/[g:1:4]").unwrap();
+        assert_eq!(parsed.to_url().unwrap(), url);
+    }
+}
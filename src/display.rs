@@ -0,0 +1,266 @@
+//! Rendering steam ids in any format.
+//!
+//! Any steam id can be rendered in any of the formats using the `display` methods,
+//! which convert the id first and report the conversion errors, so the value
+//! returned can be formatted without failing:
+//!
+//! ```rust
+//! use steamidfx::display::Format;
+//! use steamidfx::id::{Id, Id32, Id64};
+//!
+//! let id = Id::Id32(Id32("STEAM_0:0:11526534".to_owned()));
+//! assert_eq!(id.display(Format::Id3Bracketed).unwrap().to_string(), "[U:1:23053068]");
+//! assert_eq!(id.display(Format::Id64).unwrap().to_string(), "76561197983318796");
+//! assert!(Id64(103582791429521412).display(Format::Id32(Default::default())).is_err());
+//! ```
+//!
+//! The ids are displayed as they are held, and the alternate flag (`{:#}`) displays
+//! them the way valve renders them, as the steam id 3 in square brackets:
+//!
+//! ```rust
+//! use steamidfx::id::{Id32, Id64};
+//!
+//! let id = Id64(76561197983318796);
+//! assert_eq!(format!("{}", id), "76561197983318796");
+//! assert_eq!(format!("{:#}", id), "[U:1:23053068]");
+//! assert_eq!(format!("{:#}", Id32("STEAM_0:0:11526534".to_owned())), "[U:1:23053068]");
+//! assert_eq!(
+//!     id.info().unwrap().to_string(),
+//!     "Individual account 11526534 (authentication server 0, instance 1) in the Public universe"
+//! );
+//! ```
+use crate::id::{Id, Id3, Id32, Id32Fields, Id32UniversePolicy, Id3Fields, Id64, Info};
+use alloc::string::String;
+use core::convert::TryFrom;
+use core::fmt;
+
+/// The text format of a steam id.
+#[derive(Debug, Copy, Clone, Default, Ord, PartialOrd, Hash, PartialEq, Eq)]
+pub enum Format {
+    /// The steam id 64: `76561197983318796`. This is the default.
+    #[default]
+    Id64,
+    /// The steam id 32, with the universe digit written according to the policy:
+    /// `STEAM_0:0:11526534`. Only the individual accounts can be represented this way.
+    Id32(Id32UniversePolicy),
+    /// The steam id 3 without the square brackets: `U:1:23053068`.
+    Id3,
+    /// The steam id 3 in square brackets, the way valve renders it: `[U:1:23053068]`.
+    Id3Bracketed,
+}
+
+/// A steam id converted to the format and ready to be displayed. Created by the `display`
+/// methods of the ids.
+#[derive(Debug, Copy, Clone)]
+pub struct Formatted(Rendered);
+
+/// The fields of the steam id in the format it is displayed in.
+#[derive(Debug, Copy, Clone)]
+enum Rendered {
+    Id64(u64),
+    Id32(Id32Fields),
+    Id3 { fields: Id3Fields, brackets: bool },
+}
+
+impl Id64 {
+    /// Converts the id to the format passed, to be displayed.
+    ///
+    /// # Errors
+    /// Returns an error if the id is incorrect or can't be represented in the format.
+    pub fn display(self, format: Format) -> crate::error::Result<Formatted> {
+        let id3 = |brackets| -> crate::error::Result<Rendered> {
+            let fields = Id3Fields::from_info(&self.info()?)?;
+            Ok(Rendered::Id3 { fields, brackets })
+        };
+        Ok(Formatted(match format {
+            Format::Id64 => Rendered::Id64(self.0),
+            Format::Id32(policy) => Rendered::Id32(Id32Fields::from_id64(self, policy)?),
+            Format::Id3 => id3(false)?,
+            Format::Id3Bracketed => id3(true)?,
+        }))
+    }
+}
+
+impl Id32 {
+    /// Converts the id to the format passed, to be displayed.
+    ///
+    /// # Errors
+    /// Returns an error if the id is incorrect or can't be represented in the format.
+    pub fn display(&self, format: Format) -> crate::error::Result<Formatted> {
+        Id64::try_from(self.clone())?.display(format)
+    }
+}
+
+impl Id3 {
+    /// Converts the id to the format passed, to be displayed.
+    ///
+    /// # Errors
+    /// Returns an error if the id is incorrect or can't be represented in the format.
+    pub fn display(&self, format: Format) -> crate::error::Result<Formatted> {
+        Id64::try_from(self.clone())?.display(format)
+    }
+}
+
+impl Id {
+    /// Converts the id to the format passed, to be displayed.
+    ///
+    /// # Errors
+    /// Returns an error if the id is incorrect or can't be represented in the format.
+    pub fn display(&self, format: Format) -> crate::error::Result<Formatted> {
+        self.id64()?.display(format)
+    }
+}
+
+impl Formatted {
+    /// Writes the id without any padding.
+    fn write_to(&self, out: &mut impl fmt::Write) -> fmt::Result {
+        match &self.0 {
+            Rendered::Id64(id) => write!(out, "{id}"),
+            Rendered::Id32(fields) => write!(out, "{fields}"),
+            Rendered::Id3 {
+                fields,
+                brackets: true,
+            } => write!(out, "[{fields}]"),
+            Rendered::Id3 { fields, .. } => write!(out, "{fields}"),
+        }
+    }
+}
+
+/// Pads the id to the width requested, if any, and writes it directly otherwise.
+impl fmt::Display for Formatted {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Rendered::Id64(id) => fmt::Display::fmt(&id, fmt),
+            _ if fmt.width().is_some() => {
+                let mut rendered = String::new();
+                self.write_to(&mut rendered)?;
+                fmt.pad(&rendered)
+            }
+            _ => self.write_to(fmt),
+        }
+    }
+}
+
+/// Writes the id the way valve renders it when the alternate flag is set and the id can be
+/// rendered so, and the text passed otherwise.
+fn write_held(
+    fmt: &mut fmt::Formatter<'_>,
+    held: &dyn fmt::Display,
+    id: impl FnOnce() -> crate::error::Result<Formatted>,
+) -> fmt::Result {
+    if fmt.alternate() {
+        if let Ok(formatted) = id() {
+            return fmt::Display::fmt(&formatted, fmt);
+        }
+    }
+    fmt::Display::fmt(held, fmt)
+}
+
+impl fmt::Display for Id64 {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_held(fmt, &self.0, || self.display(Format::Id3Bracketed))
+    }
+}
+
+impl fmt::Display for Id32 {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_held(fmt, &self.0, || self.display(Format::Id3Bracketed))
+    }
+}
+
+impl fmt::Display for Id3 {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_held(fmt, &self.0, || self.display(Format::Id3Bracketed))
+    }
+}
+
+impl fmt::Display for Id {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Id::Id64(id) => fmt::Display::fmt(id, fmt),
+            Id::Id32(id) => fmt::Display::fmt(id, fmt),
+            Id::Id3(id) => fmt::Display::fmt(id, fmt),
+        }
+    }
+}
+
+impl fmt::Display for Info {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            fmt,
+            "{} account {} (authentication server {}, instance {}) in the {} universe",
+            self.account_type,
+            self.account,
+            self.authentication_server,
+            self.instance,
+            self.universe
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::unreadable_literal)]
+    #[allow(clippy::too_many_lines)]
+    #[test]
+    fn display_all_formats() {
+        let id = Id::Id3(Id3("U:1:23053069".to_owned()));
+        let formats = [
+            (Format::Id64, "76561197983318797"),
+            (
+                Format::Id32(Id32UniversePolicy::LegacyZero),
+                "STEAM_0:1:11526534",
+            ),
+            (
+                Format::Id32(Id32UniversePolicy::RealUniverse),
+                "STEAM_1:1:11526534",
+            ),
+            (Format::Id3, "U:1:23053069"),
+            (Format::Id3Bracketed, "[U:1:23053069]"),
+        ];
+        for (format, expected) in &formats {
+            assert_eq!(id.display(*format).unwrap().to_string(), *expected);
+        }
+        let lobby = Id64(109212290963734532);
+        assert_eq!(lobby.display(Format::Id3).unwrap().to_string(), "L:1:4");
+        assert!(lobby
+            .display(Format::Id32(Id32UniversePolicy::LegacyZero))
+            .is_err());
+        assert!(Id::Id3(Id3("[Q:1:5]".to_owned()))
+            .display(Format::Id64)
+            .is_err());
+    }
+
+    #[allow(clippy::unreadable_literal)]
+    #[test]
+    fn display_alternate() {
+        let id = Id::Id64(Id64(103582791429521412));
+        assert_eq!(id.to_string(), "103582791429521412");
+        assert_eq!(format!("{id:#}"), "[g:1:4]");
+        let id3 = Id3("U:1:23053068:1".to_owned());
+        assert_eq!(id3.to_string(), "U:1:23053068:1");
+        assert_eq!(format!("{id3:#}"), "[U:1:23053068]");
+        let invalid = Id32("STEAM_9:0:1".to_owned());
+        assert_eq!(format!("{invalid:#}"), "STEAM_9:0:1");
+        assert_eq!(format!("{:#}", Id64(u64::MAX)), "18446744073709551615");
+    }
+
+    #[allow(clippy::unreadable_literal)]
+    #[test]
+    fn display_padded() {
+        assert_eq!(format!("{:>20}", Id64(5)), "                   5");
+        assert_eq!(format!("{:<8}|", Id3("U:1:5".to_owned())), "U:1:5   |");
+        assert_eq!(
+            format!("{:*^22}", Id::Id32(Id32("STEAM_0:0:1".to_owned()))),
+            "*****STEAM_0:0:1******"
+        );
+        assert_eq!(
+            format!("{:>#16}", Id64(76561197983318796)),
+            "  [U:1:23053068]"
+        );
+        let formatted = Id64(5).display(Format::Id64).unwrap();
+        assert_eq!(format!("{formatted:03}"), "005");
+    }
+}
@@ -1,15 +1,195 @@
-#![allow(missing_docs)]
-error_chain::error_chain! {
-    foreign_links {
-        Fmt(::std::fmt::Error);
-        Io(::std::io::Error) #[cfg(unix)];
-        ParseInt(::std::num::ParseIntError);
+//! The errors used in this crate.
+//!
+//! Example:
+//!
+//! ```rust
+//! use std::convert::TryFrom;
+//! use steamidfx::error::Error;
+//! use steamidfx::id::{AccountType, Id32, Id64};
+//!
+//! match Id32::try_from(Id64(103582791429521412)) {
+//!     Err(Error::UnsupportedAccountType { account_type, .. }) => {
+//!         assert_eq!(account_type, AccountType::Clan)
+//!     }
+//!     _ => unreachable!(),
+//! }
+//! ```
+use crate::id::{AccountType, Universe};
+use crate::validate::{Diagnostic, Field, Rule};
+use alloc::string::String;
+use core::fmt;
+
+/// The error of this crate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// The text isn't in the format expected.
+    InvalidFormat {
+        /// What the text was expected to be, like `steam id 3` or `invite code`.
+        expected: &'static str,
+        /// The text rejected.
+        value: String,
+    },
+    /// The steam id is rejected by the validation, the diagnostic (also returned as the source of
+    /// the error) tells why.
+    Rejected(Diagnostic),
+    /// The number doesn't fit the field of the steam id or doesn't represent a known value.
+    OutOfRange {
+        /// The field of the steam id.
+        field: Field,
+        /// The number rejected.
+        value: u64,
+    },
+    /// A known universe or account type is passed as its `Unknown` variant, like
+    /// `Universe::Unknown(1)` instead of `Universe::Public`.
+    KnownAsUnknown {
+        /// The field of the steam id.
+        field: Field,
+        /// The number of the known value.
+        value: u8,
+    },
+    /// The steam id breaks one of valve's validity rules.
+    BrokenRule {
+        /// The field of the steam id which breaks the rule.
+        field: Field,
+        /// The value of the field.
+        value: u64,
+        /// The rule broken.
+        rule: Rule,
+    },
+    /// The accounts of the type don't have the value requested.
+    UnsupportedAccountType {
+        /// The account type of the steam id.
+        account_type: AccountType,
+        /// What was requested, like `a steam id 32` or `a friend code`.
+        target: &'static str,
+    },
+    /// The universe doesn't have the value requested.
+    UnsupportedUniverse {
+        /// The universe of the steam id.
+        universe: Universe,
+        /// What was requested.
+        target: &'static str,
+    },
+    /// The link doesn't contain the steam id and must be resolved using the Steam Web API first.
+    Unresolved(String),
+}
+
+/// The result with the error of this crate.
+pub type Result<T> = core::result::Result<T, Error>;
+
+impl Error {
+    /// Creates the error of the text which isn't the value expected.
+    pub(crate) fn invalid_format(expected: &'static str, value: impl Into<String>) -> Error {
+        Error::InvalidFormat {
+            expected,
+            value: value.into(),
+        }
+    }
+
+    /// Creates the error of the account type which doesn't have the target.
+    pub(crate) fn unsupported_account_type(
+        account_type: AccountType,
+        target: &'static str,
+    ) -> Error {
+        Error::UnsupportedAccountType {
+            account_type,
+            target,
+        }
     }
+}
 
-    errors {
-        InvalidSteamId(id: String) {
-            description("Invalid Steam ID.")
-            display("Invalid Steam ID: \"{}\".", id)
+impl fmt::Display for Error {
+    #[allow(clippy::too_many_lines)]
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidFormat { expected, value } => {
+                write!(fmt, "Invalid {expected}: {value:?}.")
+            }
+            Error::Rejected(_) => fmt.write_str("The steam id is rejected by the validation."),
+            Error::OutOfRange { field, value } => {
+                write!(fmt, "The {field} {value} is out of range.")
+            }
+            Error::KnownAsUnknown { field, value } => {
+                write!(fmt, "The {field} {value} is known, so it can't be Unknown.")
+            }
+            Error::BrokenRule { field, value, rule } => {
+                write!(
+                    fmt,
+                    "The {field} {value} breaks valve's validity rules: {rule}."
+                )
+            }
+            Error::UnsupportedAccountType {
+                account_type,
+                target,
+            } => write!(fmt, "{account_type} accounts don't have {target}."),
+            Error::UnsupportedUniverse { universe, target } => {
+                write!(fmt, "The {universe} universe doesn't have {target}.")
+            }
+            Error::Unresolved(link) => {
+                write!(fmt, "Resolve {link:?} using the Steam Web API first.")
+            }
         }
     }
 }
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Rejected(diagnostic) => Some(diagnostic),
+            _ => None,
+        }
+    }
+}
+
+impl From<Diagnostic> for Error {
+    fn from(diagnostic: Diagnostic) -> Self {
+        Error::Rejected(diagnostic)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::id::{Id, Id32, Id64};
+    use core::convert::TryFrom;
+
+    #[allow(clippy::unreadable_literal)]
+    #[test]
+    fn typed_errors() {
+        assert_eq!(
+            Id32::try_from(Id64(103582791429521412)).unwrap_err(),
+            Error::unsupported_account_type(AccountType::Clan, "a steam id 32")
+        );
+        assert_eq!(
+            Universe::try_from(256u64).unwrap_err(),
+            Error::OutOfRange {
+                field: Field::Universe,
+                value: 256
+            }
+        );
+        let error = "abc".parse::<Id>().unwrap_err();
+        assert_eq!(error, Error::invalid_format("steam id", "abc"));
+        assert_eq!(error.to_string(), "Invalid steam id: \"abc\".");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn error_sources() {
+        use crate::parse::ParseMode;
+        use std::error::Error as _;
+
+        assert!("abc".parse::<Id>().unwrap_err().source().is_none());
+        let error = Id::parse("STEAM_0:2:1", ParseMode::Strict).unwrap_err();
+        assert!(matches!(error, Error::Rejected(_)));
+        assert_eq!(
+            error.to_string(),
+            "The steam id is rejected by the validation."
+        );
+        assert_eq!(
+            error.source().unwrap().to_string(),
+            "Invalid authentication server at 8..9: the value is greater than 1."
+        );
+    }
+}
@@ -0,0 +1,140 @@
+//! CS:GO (and CS2) friend codes.
+//!
+//! The game shows the players a short code like `SUCVS-FADA` instead of the
+//! steam id. The code is the account id interleaved with the bits of an `MD5`
+//! hash of it, written using a base-32 alphabet.
+//!
+//! Example:
+//!
+//! ```rust
+//! use steamidfx::id::Id64;
+//!
+//! let id = Id64(76561197960287930);
+//! assert_eq!(id.to_csgo_friend_code().unwrap(), "SUCVS-FADA");
+//! assert_eq!(Id64::from_csgo_friend_code("SUCVS-FADA").unwrap(), id);
+//! ```
+use crate::account_id::{individual_account_id, AccountId};
+use crate::id::Id64;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// The base-32 alphabet of the friend codes.
+const FRIEND_CODE_ALPHABET: &[u8; 32] = b"ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
+/// The prefix which is always omitted from the friend codes of the individual accounts.
+const FRIEND_CODE_PREFIX: &str = "AAAA-";
+/// The `CSGO` string, mixed into the account id before hashing.
+const FRIEND_CODE_HASH_SALT: u64 = 0x4353_474F;
+/// The number of the base-32 digits in a full friend code.
+const FRIEND_CODE_DIGITS: usize = 13;
+
+/// Returns the 32 bits of the hash used to protect the account id bits in the code.
+fn account_id_hash(account_id: u32) -> u32 {
+    let salted = FRIEND_CODE_HASH_SALT << 32 | u64::from(account_id);
+    let digest = md5::compute(salted.to_le_bytes());
+    u32::from_le_bytes([digest[0], digest[1], digest[2], digest[3]])
+}
+
+/// Interleaves every nibble of the account id with a bit of the hash.
+fn encode_account_id(account_id: u32) -> String {
+    let hash = account_id_hash(account_id);
+    let interleaved = (0..8).fold(0u64, |result, i| {
+        let id_nibble = u64::from(account_id >> (i * 4) & 0xF);
+        let hash_bit = u64::from(hash >> i & 1);
+        result << 5 | id_nibble << 1 | hash_bit
+    });
+    let mut bits = interleaved.swap_bytes();
+    let mut code = String::with_capacity(FRIEND_CODE_DIGITS + 2);
+    for i in 0..FRIEND_CODE_DIGITS {
+        if i == 4 || i == 9 {
+            code.push('-');
+        }
+        code.push(char::from(FRIEND_CODE_ALPHABET[(bits & 0x1F) as usize]));
+        bits >>= 5;
+    }
+    code.split_off(FRIEND_CODE_PREFIX.len())
+}
+
+/// Extracts the account id from the code, without checking the hash bits.
+fn decode_account_id(code: &str) -> Option<u32> {
+    let digits: Vec<u8> = format!("{FRIEND_CODE_PREFIX}{code}")
+        .bytes()
+        .filter(|c| *c != b'-')
+        .collect();
+    if digits.len() != FRIEND_CODE_DIGITS {
+        return None;
+    }
+    let mut bits = digits.iter().enumerate().try_fold(0u64, |bits, (i, c)| {
+        let index = FRIEND_CODE_ALPHABET.iter().position(|d| d == c)? as u64;
+        Some(bits | index << (5 * i))
+    })?;
+    bits = bits.swap_bytes();
+    Some((0..8).fold(0u32, |account_id, _| {
+        bits >>= 1;
+        let nibble = (bits & 0xF) as u32;
+        bits >>= 4;
+        account_id << 4 | nibble
+    }))
+}
+
+impl Id64 {
+    /// Returns the CS:GO friend code of this individual account, like `SUCVS-FADA`. The code
+    /// carries just the account id, see [`AccountId`] for which ids convert.
+    ///
+    /// # Errors
+    /// Returns an error if the id is incorrect or can't be written as a friend code.
+    pub fn to_csgo_friend_code(self) -> crate::error::Result<String> {
+        let account_id = individual_account_id(&self.info()?, "a friend code")?;
+        Ok(encode_account_id(account_id.0))
+    }
+
+    /// Parses the CS:GO friend code, like `SUCVS-FADA`, into the steam id its account id
+    /// converts back to.
+    ///
+    /// # Errors
+    /// Returns an error if the code is malformed or its check bits don't match the account id.
+    pub fn from_csgo_friend_code(code: &str) -> crate::error::Result<Id64> {
+        let account_id = decode_account_id(code)
+            .filter(|account_id| encode_account_id(*account_id) == code)
+            .ok_or_else(|| crate::error::Error::invalid_format("friend code", code))?;
+        Ok(Id64::from(AccountId(account_id)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::unreadable_literal)]
+    #[test]
+    fn friend_code_known_pairs() {
+        let pairs = [
+            (76561197960287930u64, "SUCVS-FADA"),
+            (76561197960265729, "AJJJS-ABAA"),
+        ];
+        for (id, code) in &pairs {
+            assert_eq!(Id64(*id).to_csgo_friend_code().unwrap(), *code);
+            assert_eq!(Id64::from_csgo_friend_code(code).unwrap(), Id64(*id));
+        }
+    }
+
+    #[allow(clippy::unreadable_literal)]
+    #[test]
+    fn friend_code_round_trip() {
+        for id in &[76561197983318796u64, 76561198000000001, 76561202255233023] {
+            let code = Id64(*id).to_csgo_friend_code().unwrap();
+            assert_eq!(Id64::from_csgo_friend_code(&code).unwrap(), Id64(*id));
+        }
+        assert!(Id64(103582791429521412).to_csgo_friend_code().is_err());
+        assert!(Id64(148618792021246732).to_csgo_friend_code().is_err());
+        assert!(Id64(76561210868220684).to_csgo_friend_code().is_err());
+    }
+
+    #[test]
+    fn friend_code_invalid() {
+        assert!(Id64::from_csgo_friend_code("SUCVS-FADB").is_err());
+        assert!(Id64::from_csgo_friend_code("SUCVS-FAD").is_err());
+        assert!(Id64::from_csgo_friend_code("SUCVS-FAD1").is_err());
+        assert!(Id64::from_csgo_friend_code("").is_err());
+    }
+}
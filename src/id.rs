@@ -1,18 +1,27 @@
 //! Steam ID and all it needs and may represent.
 //!
 //! Please check out the structures for more information.
-use crate::bit_iterator::BitIterator;
-use regex::Regex;
+use crate::account_id::AccountId;
+use crate::parse::Cursor;
+use crate::validate::Field;
+use alloc::borrow::ToOwned;
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::convert::TryFrom;
+#[cfg(feature = "serialization")]
+use core::str::FromStr;
 #[cfg(feature = "serialization")]
 use serde::de::{self, Visitor};
-use std::collections::HashMap;
-use std::convert::TryFrom;
-use std::convert::TryInto;
-use std::str::FromStr;
 
 const DEFAULT_STEAM_ACCOUNT_TYPE: u8 = 1;
 // The steam id community page just try to set it to `1` if you don't know the value.
 const DEFAULT_STEAM_ACCOUNT_INSTANCE: u8 = 1;
+/// The maximum account number, as it takes 31 bits of the steam id 64.
+pub(crate) const MAX_ACCOUNT: u32 = 0x7FFF_FFFF;
+/// The maximum account instance, as it takes 20 bits of the steam id 64.
+pub(crate) const MAX_INSTANCE: u32 = 0xF_FFFF;
+/// The maximum account type, as it takes 4 bits of the steam id 64.
+const MAX_ACCOUNT_TYPE: u8 = 0xF;
 
 /// Steam online state.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -30,8 +39,8 @@ pub enum OnlineState {
     #[cfg_attr(feature = "serialization", serde(other))]
     Other,
 }
-impl std::fmt::Display for OnlineState {
-    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Display for OnlineState {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
         fmt.write_str(match *self {
             OnlineState::Offline => "Offline",
             OnlineState::Online => "Online",
@@ -41,57 +50,120 @@ impl std::fmt::Display for OnlineState {
     }
 }
 
-/// Steam Id Universe.
+/// Steam Id Universe, the same as valve's `EUniverse`.
 #[derive(Debug, Copy, Clone, Ord, PartialOrd, Hash, PartialEq, Eq)]
 #[cfg_attr(
     feature = "serialization",
     derive(serde::Serialize, serde::Deserialize)
 )]
+#[non_exhaustive]
 pub enum Universe {
-    /// An individual account or unspecified.
-    IndividualOrUnspecified = 0,
-    /// A public account.
-    Public = 1,
-    /// A beta account.
-    Beta = 2,
-    /// Internal (related to Valve?) account.
-    Internal = 3,
-    /// A developer account (a game developer's?).
-    Developer = 4,
-    /// Can't remember already. Fix this :-)
-    Rc = 5,
-}
-
-impl std::fmt::Display for Universe {
-    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    /// The invalid universe, also used when the universe is unspecified.
+    #[cfg_attr(feature = "serialization", serde(alias = "IndividualOrUnspecified"))]
+    Invalid,
+    /// The public universe, the one all the users are in.
+    Public,
+    /// The beta universe.
+    Beta,
+    /// The internal universe of valve.
+    Internal,
+    /// The universe of valve's developers.
+    Developer,
+    /// The release candidate universe, which valve doesn't use anymore.
+    Rc,
+    /// A universe unknown to this crate, with its number.
+    Unknown(u8),
+}
+
+impl core::fmt::Display for Universe {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         fmt.write_str(match self {
-            Universe::IndividualOrUnspecified => "Individual or unspecified",
+            Universe::Invalid => "Invalid",
             Universe::Public => "Public",
             Universe::Beta => "Beta",
             Universe::Internal => "Internal",
             Universe::Developer => "Developer",
             Universe::Rc => "RC",
+            Universe::Unknown(universe) => return write!(fmt, "Unknown ({universe})"),
         })
     }
 }
 
-impl std::convert::TryFrom<u64> for Universe {
-    type Error = crate::error::Error;
-
-    fn try_from(value: u64) -> crate::error::Result<Self> {
-        Ok(match value {
-            0 => Universe::IndividualOrUnspecified,
+impl Universe {
+    /// Returns the universe the number represents, which is [`Universe::Unknown`] for the numbers
+    /// unknown to this crate.
+    #[must_use]
+    pub const fn from_u8(value: u8) -> Universe {
+        match value {
+            0 => Universe::Invalid,
             1 => Universe::Public,
             2 => Universe::Beta,
             3 => Universe::Internal,
             4 => Universe::Developer,
             5 => Universe::Rc,
-            _ => return Err("The number doesn't represent a correct steam id universe.".into()),
-        })
+            value => Universe::Unknown(value),
+        }
+    }
+
+    /// Returns the number of the universe.
+    #[must_use]
+    pub const fn to_u8(self) -> u8 {
+        match self {
+            Universe::Invalid => 0,
+            Universe::Public => 1,
+            Universe::Beta => 2,
+            Universe::Internal => 3,
+            Universe::Developer => 4,
+            Universe::Rc => 5,
+            Universe::Unknown(value) => value,
+        }
+    }
+
+    /// Returns `true` if the universe is known to this crate.
+    #[must_use]
+    pub const fn is_known(self) -> bool {
+        !matches!(self, Universe::Unknown(_))
+    }
+
+    /// Returns `false` for [`Universe::Unknown`] carrying the number of a known universe, which
+    /// is read back from the id as that known universe.
+    pub(crate) const fn is_canonical(self) -> bool {
+        match self {
+            Universe::Unknown(value) => !Universe::from_u8(value).is_known(),
+            _ => true,
+        }
+    }
+
+    /// Returns the known universe the number represents, if any.
+    #[allow(clippy::cast_possible_truncation)]
+    pub(crate) const fn from_bits(value: u64) -> Option<Universe> {
+        match Universe::from_u8(value as u8) {
+            universe if value <= u8::MAX as u64 && universe.is_known() => Some(universe),
+            _ => None,
+        }
+    }
+}
+
+impl From<Universe> for u8 {
+    fn from(universe: Universe) -> Self {
+        universe.to_u8()
+    }
+}
+
+impl core::convert::TryFrom<u64> for Universe {
+    type Error = crate::error::Error;
+
+    fn try_from(value: u64) -> crate::error::Result<Self> {
+        u8::try_from(value)
+            .map(Universe::from_u8)
+            .map_err(|_| crate::error::Error::OutOfRange {
+                field: Field::Universe,
+                value,
+            })
     }
 }
 
-impl std::convert::TryFrom<u32> for Universe {
+impl core::convert::TryFrom<u32> for Universe {
     type Error = crate::error::Error;
 
     fn try_from(value: u32) -> crate::error::Result<Self> {
@@ -99,7 +171,7 @@ impl std::convert::TryFrom<u32> for Universe {
     }
 }
 
-impl std::convert::TryFrom<u16> for Universe {
+impl core::convert::TryFrom<u16> for Universe {
     type Error = crate::error::Error;
 
     fn try_from(value: u16) -> crate::error::Result<Self> {
@@ -107,7 +179,7 @@ impl std::convert::TryFrom<u16> for Universe {
     }
 }
 
-impl std::convert::TryFrom<u8> for Universe {
+impl core::convert::TryFrom<u8> for Universe {
     type Error = crate::error::Error;
 
     fn try_from(value: u8) -> crate::error::Result<Self> {
@@ -115,40 +187,135 @@ impl std::convert::TryFrom<u8> for Universe {
     }
 }
 
-/// Steam Id Account type.
+/// Steam Id Account type, the same as valve's `EAccountType`.
+///
+/// When deserialized, the names unknown to this crate are read as [`AccountType::Invalid`].
 #[derive(Debug, Copy, Clone, Ord, PartialOrd, Hash, PartialEq, Eq)]
-#[cfg_attr(
-    feature = "serialization",
-    derive(serde::Serialize, serde::Deserialize)
-)]
+#[cfg_attr(feature = "serialization", derive(serde::Serialize))]
+#[non_exhaustive]
 pub enum AccountType {
+    /// An invalid account type.
+    Invalid,
     /// The account is of an individual.
-    Individual = 1,
-    /// The account is of a multiseat type.
-    Multiseat = 2,
+    Individual,
+    /// The account is of a multiseat type (e.g. a cybercafe).
+    Multiseat,
     /// The account is of a game server (registered in steam).
-    GameServer = 3,
+    GameServer,
     /// The account is of a game server (unregistered in steam).
-    AnonymousGameServer = 4,
+    AnonymousGameServer,
     /// The account is pending approval from Valve.
-    Pending = 5,
+    Pending,
     /// The account is of a content server.
-    ContentServer = 6,
+    ContentServer,
     /// The account is of a clan.
-    Clan = 7,
+    Clan,
     /// The chat account.
-    Chat = 8,
-    /// The P2P seeder account.
-    PeerToPeerSuperSeeder = 9,
+    Chat,
+    /// The fake account of a local user of a console network, like PSN or Xbox Live.
+    ConsoleUser,
     /// The anonymous user account.
-    AnonymousUser = 10,
-    /// An invalid account type.
-    #[cfg_attr(feature = "serialization", serde(other))]
-    Invalid = 0,
+    AnonymousUser,
+    /// An account type unknown to this crate, with its number.
+    Unknown(u8),
 }
 
-impl std::fmt::Display for AccountType {
-    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl AccountType {
+    /// Returns the instance used for the account type when it is not specified explicitly:
+    /// the desktop instance (`1`) for the individual accounts and `0` for all the others.
+    #[must_use]
+    pub const fn default_instance(self) -> u32 {
+        match self {
+            AccountType::Individual => DEFAULT_STEAM_ACCOUNT_INSTANCE as u32,
+            _ => 0,
+        }
+    }
+
+    /// Returns the account type the number represents, which is [`AccountType::Unknown`] for the
+    /// numbers unknown to this crate.
+    #[must_use]
+    pub const fn from_u8(value: u8) -> AccountType {
+        match value {
+            0 => AccountType::Invalid,
+            1 => AccountType::Individual,
+            2 => AccountType::Multiseat,
+            3 => AccountType::GameServer,
+            4 => AccountType::AnonymousGameServer,
+            5 => AccountType::Pending,
+            6 => AccountType::ContentServer,
+            7 => AccountType::Clan,
+            8 => AccountType::Chat,
+            9 => AccountType::ConsoleUser,
+            10 => AccountType::AnonymousUser,
+            value => AccountType::Unknown(value),
+        }
+    }
+
+    /// Returns the number of the account type.
+    #[must_use]
+    pub const fn to_u8(self) -> u8 {
+        match self {
+            AccountType::Invalid => 0,
+            AccountType::Individual => 1,
+            AccountType::Multiseat => 2,
+            AccountType::GameServer => 3,
+            AccountType::AnonymousGameServer => 4,
+            AccountType::Pending => 5,
+            AccountType::ContentServer => 6,
+            AccountType::Clan => 7,
+            AccountType::Chat => 8,
+            AccountType::ConsoleUser => 9,
+            AccountType::AnonymousUser => 10,
+            AccountType::Unknown(value) => value,
+        }
+    }
+
+    /// Returns `true` if the account type is known to this crate.
+    #[must_use]
+    pub const fn is_known(self) -> bool {
+        !matches!(self, AccountType::Unknown(_))
+    }
+
+    /// Returns `false` for [`AccountType::Unknown`] carrying the number of a known account type,
+    /// which is read back from the id as that known account type.
+    pub(crate) const fn is_canonical(self) -> bool {
+        match self {
+            AccountType::Unknown(value) => !AccountType::from_u8(value).is_known(),
+            _ => true,
+        }
+    }
+
+    /// Returns the known account type the number represents, if any.
+    #[allow(clippy::cast_possible_truncation)]
+    pub(crate) const fn from_bits(value: u64) -> Option<AccountType> {
+        match AccountType::from_u8(value as u8) {
+            account_type if value <= u8::MAX as u64 && account_type.is_known() => {
+                Some(account_type)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the account type the steam id 3 letter represents, if any.
+    pub(crate) const fn from_letter(value: char) -> Option<AccountType> {
+        Some(match value {
+            'I' => AccountType::Invalid,
+            'U' => AccountType::Individual,
+            'M' => AccountType::Multiseat,
+            'G' => AccountType::GameServer,
+            'A' => AccountType::AnonymousGameServer,
+            'P' => AccountType::Pending,
+            'C' => AccountType::ContentServer,
+            'g' => AccountType::Clan,
+            'T' | 'L' | 'c' => AccountType::Chat,
+            'a' => AccountType::AnonymousUser,
+            _ => return None,
+        })
+    }
+}
+
+impl core::fmt::Display for AccountType {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         fmt.write_str(match self {
             AccountType::Individual => "Individual",
             AccountType::Multiseat => "Multiseat",
@@ -158,82 +325,330 @@ impl std::fmt::Display for AccountType {
             AccountType::ContentServer => "Content server",
             AccountType::Clan => "Clan",
             AccountType::Chat => "Chat",
-            AccountType::PeerToPeerSuperSeeder => "Peer to peer superseeder",
+            AccountType::ConsoleUser => "Console user",
             AccountType::AnonymousUser => "Anonymous user",
             AccountType::Invalid => "Invalid",
+            AccountType::Unknown(account_type) => return write!(fmt, "Unknown ({account_type})"),
         })
     }
 }
 
-lazy_static::lazy_static! {
-    static ref ACCOUNT_TYPE_MAP: HashMap<char, AccountType> = vec![
-        ('I', AccountType::Invalid),
-        ('U', AccountType::Individual),
-        ('M', AccountType::Multiseat),
-        ('G', AccountType::GameServer),
-        ('A', AccountType::AnonymousGameServer),
-        ('P', AccountType::Pending),
-        ('C', AccountType::ContentServer),
-        ('g', AccountType::Clan),
-        ('T', AccountType::Chat),
-        ('L', AccountType::Chat),
-        ('c', AccountType::Chat),
-        ('a', AccountType::AnonymousUser),
-    ].into_iter().collect();
+/// The account type as it is deserialized: the `Unknown` variant keeps the numbers unknown to
+/// this crate, while the names unknown to it are read as [`AccountType::Invalid`], as `serde`
+/// can't combine `#[serde(other)]` with the newtype variant of [`AccountType`] itself.
+#[cfg(feature = "serialization")]
+#[derive(serde::Deserialize)]
+#[serde(rename = "AccountType")]
+enum AccountTypeName {
+    Invalid,
+    Individual,
+    Multiseat,
+    GameServer,
+    AnonymousGameServer,
+    Pending,
+    ContentServer,
+    Clan,
+    Chat,
+    #[serde(alias = "PeerToPeerSuperSeeder")]
+    ConsoleUser,
+    AnonymousUser,
+    Unknown(u8),
+    #[serde(other)]
+    Other,
+}
 
-    static ref ID32_REGEXP: Regex = {
-        Regex::new(r"^STEAM_(\d):(\d):(\d+)$").unwrap()
-    };
+#[cfg(feature = "serialization")]
+impl<'de> serde::Deserialize<'de> for AccountType {
+    fn deserialize<D>(deserializer: D) -> core::result::Result<AccountType, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Ok(match AccountTypeName::deserialize(deserializer)? {
+            AccountTypeName::Invalid | AccountTypeName::Other => AccountType::Invalid,
+            AccountTypeName::Individual => AccountType::Individual,
+            AccountTypeName::Multiseat => AccountType::Multiseat,
+            AccountTypeName::GameServer => AccountType::GameServer,
+            AccountTypeName::AnonymousGameServer => AccountType::AnonymousGameServer,
+            AccountTypeName::Pending => AccountType::Pending,
+            AccountTypeName::ContentServer => AccountType::ContentServer,
+            AccountTypeName::Clan => AccountType::Clan,
+            AccountTypeName::Chat => AccountType::Chat,
+            AccountTypeName::ConsoleUser => AccountType::ConsoleUser,
+            AccountTypeName::AnonymousUser => AccountType::AnonymousUser,
+            AccountTypeName::Unknown(value) => AccountType::Unknown(value),
+        })
+    }
+}
 
-    static ref ID3_REGEXP: Regex = {
-        Regex::new(r"^(\w):(\d):(\d+)$").unwrap()
-    };
+/// Converts the number to `u32` if it fits, as `u32::try_from` can't be used in the `const fn`s.
+#[allow(clippy::cast_possible_truncation)]
+const fn to_u32(value: u64) -> Option<u32> {
+    if value > u32::MAX as u64 {
+        None
+    } else {
+        Some(value as u32)
+    }
 }
 
-impl std::str::FromStr for AccountType {
+/// Strips the square brackets around the steam id 3, if there are any.
+const fn unbracketed(value: &[u8]) -> &[u8] {
+    match value {
+        [b'[', unbracketed @ .., b']'] => unbracketed,
+        _ => value,
+    }
+}
+
+/// The fields of a steam id 32 exactly as they are written in the text form.
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct Id32Fields {
+    /// The universe digit, the `X` in `STEAM_X:Y:Z`.
+    pub(crate) universe_digit: u8,
+    /// The authentication server bit, the `Y` in `STEAM_X:Y:Z`.
+    pub(crate) authentication_server: u8,
+    /// The account number, the `Z` in `STEAM_X:Y:Z`.
+    pub(crate) account: u32,
+}
+
+impl Id32Fields {
+    /// Parses the steam id 32.
+    pub(crate) fn parse(value: &str) -> crate::error::Result<Id32Fields> {
+        Id32Fields::parse_bytes(value.as_bytes())
+            .ok_or_else(|| crate::error::Error::invalid_format("steam id 32", value))
+    }
+
+    /// Parses the steam id 32 from the bytes, without allocating.
+    pub(crate) const fn parse_bytes(value: &[u8]) -> Option<Id32Fields> {
+        let cursor = const_try!(Cursor::new(value).eat_prefix(b"STEAM_"));
+        let (universe_digit, cursor) = const_try!(cursor.digit());
+        let (authentication_server, cursor) = const_try!(const_try!(cursor.eat(b':')).digit());
+        let (account, cursor) = const_try!(cursor.number_after(b':'));
+        if !cursor.is_end() {
+            return None;
+        }
+        Some(Id32Fields {
+            universe_digit,
+            authentication_server,
+            account: const_try!(to_u32(account)),
+        })
+    }
+
+    /// Collects the fields of the steam id 32 of the individual account, writing the universe
+    /// digit according to the policy passed.
+    pub(crate) fn from_id64(
+        id: Id64,
+        policy: Id32UniversePolicy,
+    ) -> crate::error::Result<Id32Fields> {
+        let info = id.info()?;
+        if info.account_type != AccountType::Individual {
+            return Err(crate::error::Error::unsupported_account_type(
+                info.account_type,
+                "a steam id 32",
+            ));
+        }
+        if info.instance != AccountType::Individual.default_instance() {
+            return Err(crate::error::Error::OutOfRange {
+                field: Field::Instance,
+                value: u64::from(info.instance),
+            });
+        }
+        Ok(Id32Fields {
+            universe_digit: id32_universe_digit(info.universe, policy)?,
+            authentication_server: info.authentication_server,
+            account: info.account,
+        })
+    }
+
+    /// Returns the universe: both `0` and `1` mean the public universe.
+    pub(crate) fn universe(self) -> crate::error::Result<Universe> {
+        self.checked_universe()
+            .ok_or(crate::error::Error::OutOfRange {
+                field: Field::Universe,
+                value: u64::from(self.universe_digit),
+            })
+    }
+
+    /// Returns the universe, if the digit represents one.
+    pub(crate) const fn checked_universe(self) -> Option<Universe> {
+        match self.universe_digit {
+            0 => Some(Universe::Public),
+            digit => Universe::from_bits(digit as u64),
+        }
+    }
+}
+
+impl core::fmt::Display for Id32Fields {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            fmt,
+            "STEAM_{}:{}:{}",
+            self.universe_digit, self.authentication_server, self.account
+        )
+    }
+}
+
+/// The fields of a steam id 3 exactly as they are written in the text form.
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct Id3Fields {
+    /// The account type, as the letter means it.
+    pub(crate) account_type: AccountType,
+    /// The account type letter.
+    letter: char,
+    /// The middle number.
+    pub(crate) universe: u8,
+    /// The account id, the last number of a steam id 3 without the instance.
+    pub(crate) account_id: AccountId,
+    /// The optional instance, written as the fourth number.
+    instance: Option<u32>,
+}
+
+impl Id3Fields {
+    /// Parses the steam id 3 in both the bare (`U:1:123`) and the bracketed (`[U:1:123]`) forms,
+    /// with an optional instance (`[A:1:123:4567]`).
+    fn parse(value: &str) -> crate::error::Result<Id3Fields> {
+        Id3Fields::parse_bytes(value.as_bytes())
+            .ok_or_else(|| crate::error::Error::invalid_format("steam id 3", value))
+    }
+
+    /// Parses the steam id 3 from the bytes, without allocating.
+    pub(crate) const fn parse_bytes(value: &[u8]) -> Option<Id3Fields> {
+        let (letter, cursor) = const_try!(Cursor::new(unbracketed(value)).next());
+        let (universe, cursor) = const_try!(const_try!(cursor.eat(b':')).digit());
+        let (account_id, cursor) = const_try!(cursor.number_after(b':'));
+        let (instance, cursor) = match cursor.number_after(b':') {
+            Some((instance, cursor)) => (Some(const_try!(to_u32(instance))), cursor),
+            None => (None, cursor),
+        };
+        if !cursor.is_end() {
+            return None;
+        }
+        Some(Id3Fields {
+            account_type: const_try!(AccountType::from_letter(letter as char)),
+            letter: letter as char,
+            universe,
+            account_id: AccountId(const_try!(to_u32(account_id))),
+            instance,
+        })
+    }
+
+    /// Collects the fields of the steam id 3 from the information about the account.
+    pub(crate) fn from_info(info: &Info) -> crate::error::Result<Id3Fields> {
+        let letter = id3_letter(info.account_type, info.instance).ok_or_else(|| {
+            crate::error::Error::unsupported_account_type(info.account_type, "a steam id 3")
+        })?;
+        if !info.universe.is_known() {
+            return Err(crate::error::Error::UnsupportedUniverse {
+                universe: info.universe,
+                target: "a steam id 3",
+            });
+        }
+        Ok(Id3Fields {
+            account_type: info.account_type,
+            letter,
+            universe: info.universe.to_u8(),
+            account_id: info.account_id(),
+            instance: Some(info.instance),
+        })
+    }
+
+    /// Returns the instance: either the one written explicitly or the one the letter implies.
+    pub(crate) const fn instance(&self) -> u32 {
+        match self.instance {
+            Some(instance) => instance,
+            None => implied_instance(self.letter, self.account_type),
+        }
+    }
+
+    /// Renders the fields the way valve's `CSteamID::Render` does: the instance is written for
+    /// the account types where it is always meaningful, and for all the others only when it
+    /// differs from the one the letter implies, so that no information is lost.
+    fn render(&self) -> String {
+        format!("[{self}]")
+    }
+}
+
+/// Writes the fields without the square brackets, the way [`Id3Fields::render`] does.
+impl core::fmt::Display for Id3Fields {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let instance = self.instance();
+        let letter = id3_letter(self.account_type, instance).unwrap_or(self.letter);
+        write!(fmt, "{}:{}:{}", letter, self.universe, self.account_id)?;
+        if letter == 'A' || letter == 'M' || instance != implied_instance(letter, self.account_type)
+        {
+            write!(fmt, ":{instance}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the instance the steam id 3 letter implies when the instance isn't written.
+const fn implied_instance(letter: char, account_type: AccountType) -> u32 {
+    match letter {
+        'c' => ChatInstanceFlags::CLAN.0,
+        'L' => ChatInstanceFlags::LOBBY.0,
+        _ => account_type.default_instance(),
+    }
+}
+
+/// Returns the letter representing the account type in the steam id 3. The chat accounts are
+/// represented by different letters depending on the chat instance flags. The console users
+/// and the unknown account types don't have a letter.
+fn id3_letter(account_type: AccountType, instance: u32) -> Option<char> {
+    Some(match account_type {
+        AccountType::Invalid => 'I',
+        AccountType::Individual => 'U',
+        AccountType::Multiseat => 'M',
+        AccountType::GameServer => 'G',
+        AccountType::AnonymousGameServer => 'A',
+        AccountType::Pending => 'P',
+        AccountType::ContentServer => 'C',
+        AccountType::Clan => 'g',
+        AccountType::Chat if instance & ChatInstanceFlags::CLAN.0 != 0 => 'c',
+        AccountType::Chat if instance & ChatInstanceFlags::LOBBY.0 != 0 => 'L',
+        AccountType::Chat => 'T',
+        AccountType::AnonymousUser => 'a',
+        AccountType::ConsoleUser | AccountType::Unknown(_) => return None,
+    })
+}
+
+impl core::str::FromStr for AccountType {
     type Err = crate::error::Error;
 
     fn from_str(s: &str) -> crate::error::Result<Self> {
-        if s.len() > 1 {
-            Err("The passed string is too long to represent a steam account type.".into())
-        } else {
-            Self::try_from(s.chars().next().unwrap()) // safe cuz we have checked the string length.
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(letter), None) => Self::try_from(letter),
+            _ => Err(crate::error::Error::invalid_format(
+                "account type letter",
+                s,
+            )),
         }
     }
 }
 
-impl std::convert::TryFrom<char> for AccountType {
+impl core::convert::TryFrom<char> for AccountType {
     type Error = crate::error::Error;
 
     fn try_from(value: char) -> crate::error::Result<Self> {
-        Ok(*ACCOUNT_TYPE_MAP
-            .get(&value)
-            .ok_or_else(|| "The string doesn't contain a correct id account type.".to_owned())?)
+        AccountType::from_letter(value).ok_or_else(|| {
+            crate::error::Error::invalid_format("account type letter", value.to_string())
+        })
     }
 }
 
-impl std::convert::TryFrom<u64> for AccountType {
+impl core::convert::TryFrom<u64> for AccountType {
     type Error = crate::error::Error;
 
     fn try_from(value: u64) -> crate::error::Result<Self> {
-        Ok(match value {
-            0 => AccountType::Invalid,
-            1 => AccountType::Individual,
-            2 => AccountType::Multiseat,
-            3 => AccountType::GameServer,
-            4 => AccountType::AnonymousGameServer,
-            5 => AccountType::Pending,
-            6 => AccountType::ContentServer,
-            7 => AccountType::Clan,
-            8 => AccountType::Chat,
-            9 => AccountType::PeerToPeerSuperSeeder,
-            10 => AccountType::AnonymousUser,
-            _ => return Err("The number doesn't represent a correct steam id universe.".into()),
-        })
+        u8::try_from(value)
+            .map(AccountType::from_u8)
+            .map_err(|_| crate::error::Error::OutOfRange {
+                field: Field::AccountType,
+                value,
+            })
     }
 }
 
-impl std::convert::TryFrom<u32> for AccountType {
+impl core::convert::TryFrom<u32> for AccountType {
     type Error = crate::error::Error;
 
     fn try_from(value: u32) -> crate::error::Result<Self> {
@@ -241,7 +656,7 @@ impl std::convert::TryFrom<u32> for AccountType {
     }
 }
 
-impl std::convert::TryFrom<u16> for AccountType {
+impl core::convert::TryFrom<u16> for AccountType {
     type Error = crate::error::Error;
 
     fn try_from(value: u16) -> crate::error::Result<Self> {
@@ -249,7 +664,7 @@ impl std::convert::TryFrom<u16> for AccountType {
     }
 }
 
-impl std::convert::TryFrom<u8> for AccountType {
+impl core::convert::TryFrom<u8> for AccountType {
     type Error = crate::error::Error;
 
     fn try_from(value: u8) -> crate::error::Result<Self> {
@@ -276,6 +691,60 @@ pub struct Info {
     pub authentication_server: u8,
 }
 
+impl Info {
+    /// Returns the chat instance flags stored in the top bits of the instance, if the account is
+    /// a chat.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use steamidfx::id::{ChatInstanceFlags, Id3};
+    ///
+    /// let info = Id3("[c:1:4]".to_owned()).info().unwrap();
+    /// assert_eq!(info.chat_instance_flags(), Some(ChatInstanceFlags::CLAN));
+    /// let info = Id3("[g:1:4]".to_owned()).info().unwrap();
+    /// assert_eq!(info.chat_instance_flags(), None);
+    /// ```
+    #[must_use]
+    pub fn chat_instance_flags(&self) -> Option<ChatInstanceFlags> {
+        if self.account_type == AccountType::Chat {
+            Some(ChatInstanceFlags(self.instance & ChatInstanceFlags::ALL.0))
+        } else {
+            None
+        }
+    }
+}
+
+/// The flags valve stores in the top bits of the instance of the chat accounts.
+/// They distinguish the clan chat rooms and the lobbies from the usual group chats.
+#[derive(Debug, Copy, Clone, Default, Ord, PartialOrd, Hash, PartialEq, Eq)]
+pub struct ChatInstanceFlags(pub u32);
+
+impl ChatInstanceFlags {
+    /// The chat room of a clan. Rendered as `c` in the steam id 3.
+    pub const CLAN: ChatInstanceFlags = ChatInstanceFlags(0x8_0000);
+    /// A lobby. Rendered as `L` in the steam id 3.
+    pub const LOBBY: ChatInstanceFlags = ChatInstanceFlags(0x4_0000);
+    /// A matchmaking lobby.
+    pub const MMS_LOBBY: ChatInstanceFlags = ChatInstanceFlags(0x2_0000);
+    /// All the known flags.
+    pub const ALL: ChatInstanceFlags = ChatInstanceFlags(0xE_0000);
+
+    /// Returns `true` if all the `other` flags are set.
+    #[must_use]
+    pub fn contains(self, other: ChatInstanceFlags) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl core::ops::BitOr for ChatInstanceFlags {
+    type Output = ChatInstanceFlags;
+
+    fn bitor(self, other: ChatInstanceFlags) -> ChatInstanceFlags {
+        ChatInstanceFlags(self.0 | other.0)
+    }
+}
+
 /// Steam Id 64.
 /// Example: `7656119xxxxxxxxxx`.
 #[allow(clippy::module_name_repetitions)]
@@ -286,24 +755,64 @@ pub struct Info {
 )]
 pub struct Id64(pub u64);
 impl Id64 {
-    /// Get a detailed information about the steam account from the steam id.
+    /// Get a detailed information about the steam account from the steam id. The universes and
+    /// the account types unknown to this crate are kept as their `Unknown` variants.
     ///
     /// # Errors
-    /// Returns an error if the account type or universe are incorrect.
-    ///
-    /// # Panics
-    /// Panics when it suddenly becomes impossible to iterate over the bits in the steam id, what in fact can't happen ever.
+    /// Doesn't return errors: any steam id 64 can be decoded. The result is kept for
+    /// compatibility.
     pub fn info(self) -> crate::error::Result<Info> {
-        let mut iter = BitIterator::new(self.0, 8);
         Ok(Info {
-            universe: iter.next().unwrap().try_into()?,
-            account_type: iter.next_bits::<u8>(4).unwrap().try_into()?,
-            instance: iter.next_bits::<u32>(20).unwrap(),
-            account: iter.next_bits::<u32>(31).unwrap(),
-            authentication_server: iter.next_bits::<u8>(1).unwrap(),
+            universe: self.universe(),
+            account_type: self.account_type(),
+            instance: self.instance(),
+            account: self.account(),
+            authentication_server: self.authentication_server(),
         })
     }
 
+    /// Returns the universe, which is [`Universe::Unknown`] if the universe bits don't represent
+    /// a known one.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn universe(self) -> Universe {
+        Universe::from_u8((self.0 >> 56) as u8)
+    }
+
+    /// Returns the account type, which is [`AccountType::Unknown`] if the account type bits don't
+    /// represent a known one.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn account_type(self) -> AccountType {
+        AccountType::from_u8((self.0 >> 52 & 0xF) as u8)
+    }
+
+    /// Returns the account instance.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn instance(self) -> u32 {
+        (self.0 >> 32) as u32 & MAX_INSTANCE
+    }
+
+    /// Returns the account id: the account number and the authentication server bit.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn account_id(self) -> AccountId {
+        AccountId(self.0 as u32)
+    }
+
+    /// Returns the account number, without the authentication server bit.
+    #[must_use]
+    pub const fn account(self) -> u32 {
+        self.account_id().account()
+    }
+
+    /// Returns the authentication server bit.
+    #[must_use]
+    pub const fn authentication_server(self) -> u8 {
+        self.account_id().authentication_server()
+    }
+
     /// Create a new Id64 with only three parameters passed, all others will be constructed using the default,
     /// most commonly used values.
     ///
@@ -334,12 +843,154 @@ impl Id64 {
         )
     }
 
-    /// Create a new Id64 with all the values specified explicitly.
+    /// Create a new Id64 of a clan from its account id, which is the number written in the steam
+    /// id 3 of the clan (`[g:1:N]`).
     ///
     /// # Errors
     /// Throws `crate::error::Error` if it is impossible to create such an id due to the width of the values passed.
-    /// To avoid this, the values passed must be used according to the specification of the steam id 64.
-    /// Also may throw a error if the `account_type` passed is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use steamidfx::id::{Id3, Id64, Universe};
+    /// use std::convert::TryFrom;
+    ///
+    /// let clan = Id64::new_clan(Universe::Public, 4).unwrap();
+    /// assert_eq!(clan, Id64(103582791429521412));
+    /// assert!(clan.is_clan());
+    /// assert_eq!(Id3::try_from(clan).unwrap(), Id3("[g:1:4]".to_owned()));
+    /// ```
+    pub fn new_clan(universe: Universe, account_id: u32) -> crate::error::Result<Id64> {
+        Id64::new_with_account_id(
+            universe,
+            AccountType::Clan,
+            AccountType::Clan.default_instance(),
+            account_id,
+        )
+    }
+
+    /// Create a new Id64 of a game server registered in steam (logged in with a game server login
+    /// token) from its account id, which is the number written in its steam id 3 (`[G:1:N]`).
+    ///
+    /// # Errors
+    /// Throws `crate::error::Error` if it is impossible to create such an id due to the width of the values passed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use steamidfx::id::{Id3, Id64, Universe};
+    /// use std::convert::TryFrom;
+    ///
+    /// let server = Id64::new_game_server(Universe::Public, 3771155, 0).unwrap();
+    /// assert_eq!(server, Id64(85568392923810579));
+    /// assert!(server.is_game_server());
+    /// assert_eq!(Id3::try_from(server).unwrap(), Id3("[G:1:3771155]".to_owned()));
+    /// ```
+    pub fn new_game_server(
+        universe: Universe,
+        account_id: u32,
+        instance: u32,
+    ) -> crate::error::Result<Id64> {
+        Id64::new_with_account_id(universe, AccountType::GameServer, instance, account_id)
+    }
+
+    /// Create a new Id64 of an anonymous game server (not logged in with a game server login token)
+    /// from its account id and instance, which are the numbers written in its steam id 3
+    /// (`[A:1:N:instance]`).
+    ///
+    /// # Errors
+    /// Throws `crate::error::Error` if it is impossible to create such an id due to the width of the values passed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use steamidfx::id::{Id3, Id64, Universe};
+    /// use std::convert::TryFrom;
+    ///
+    /// let server = Id64::new_anonymous_game_server(Universe::Public, 123, 4567).unwrap();
+    /// assert!(server.is_game_server());
+    /// assert!(server.is_anonymous_game_server());
+    /// assert_eq!(server.info().unwrap().instance, 4567);
+    /// assert_eq!(Id3::try_from(server).unwrap(), Id3("[A:1:123:4567]".to_owned()));
+    /// ```
+    pub fn new_anonymous_game_server(
+        universe: Universe,
+        account_id: u32,
+        instance: u32,
+    ) -> crate::error::Result<Id64> {
+        Id64::new_with_account_id(
+            universe,
+            AccountType::AnonymousGameServer,
+            instance,
+            account_id,
+        )
+    }
+
+    /// Create a new Id64 from the account id, which contains both the account number and the
+    /// authentication server bit.
+    fn new_with_account_id(
+        universe: Universe,
+        account_type: AccountType,
+        instance: u32,
+        account_id: u32,
+    ) -> crate::error::Result<Id64> {
+        let account_id = AccountId(account_id);
+        Id64::new_full(
+            universe,
+            account_type,
+            instance,
+            account_id.authentication_server(),
+            account_id.account(),
+        )
+    }
+
+    /// Composes the Id64 from its parts using only the bit operations. The caller must make sure
+    /// the account type fits 4 bits and the instance fits 20 bits.
+    pub(crate) const fn from_parts(
+        universe: Universe,
+        account_type: AccountType,
+        instance: u32,
+        account_id: AccountId,
+    ) -> Id64 {
+        Id64(
+            (universe.to_u8() as u64) << 56
+                | (account_type.to_u8() as u64) << 52
+                | (instance as u64) << 32
+                | account_id.0 as u64,
+        )
+    }
+
+    /// Returns `true` if the id is a correct id of a clan (a steam group).
+    #[must_use]
+    pub fn is_clan(self) -> bool {
+        matches!(self.info(), Ok(info) if info.account_type == AccountType::Clan)
+    }
+
+    /// Returns `true` if the id is a correct id of a game server, either registered in steam or
+    /// anonymous.
+    #[must_use]
+    pub fn is_game_server(self) -> bool {
+        matches!(
+            self.info(),
+            Ok(info) if info.account_type == AccountType::GameServer
+                || info.account_type == AccountType::AnonymousGameServer
+        )
+    }
+
+    /// Returns `true` if the id is a correct id of an anonymous game server.
+    #[must_use]
+    pub fn is_anonymous_game_server(self) -> bool {
+        matches!(self.info(), Ok(info) if info.account_type == AccountType::AnonymousGameServer)
+    }
+
+    /// Create a new Id64 with all the values specified explicitly.
+    ///
+    /// # Errors
+    /// Returns [`Error::OutOfRange`](crate::error::Error::OutOfRange) if a value doesn't fit its
+    /// field of the steam id 64: the account type must fit 4 bits, the instance 20 bits, the
+    /// account number 31 bits and the authentication server must be either `0` or `1`.
+    /// Returns [`Error::KnownAsUnknown`](crate::error::Error::KnownAsUnknown) if the universe or
+    /// the account type is `Unknown` with the number of a known one, like `Universe::Unknown(1)`.
     ///
     /// # Example
     ///
@@ -356,18 +1007,133 @@ impl Id64 {
         authentication_server: u8,
         account: u32,
     ) -> crate::error::Result<Id64> {
-        let num = u64::from_str_radix(
-            &format!(
-                "{:08b}{:04b}{:020b}{:031b}{:b}",
-                universe as u8,
-                account_type as u8,
-                account_instance,
-                account,
-                authentication_server
+        check_canonical(Field::Universe, universe.is_canonical(), universe.to_u8())?;
+        check_canonical(
+            Field::AccountType,
+            account_type.is_canonical(),
+            account_type.to_u8(),
+        )?;
+        check_range(Field::AccountType, account_type.to_u8(), MAX_ACCOUNT_TYPE)?;
+        check_range(Field::Instance, account_instance, MAX_INSTANCE)?;
+        check_range(Field::Account, account, MAX_ACCOUNT)?;
+        check_range(Field::AuthenticationServer, authentication_server, 1)?;
+        Ok(Id64::from_parts(
+            universe,
+            account_type,
+            account_instance,
+            AccountId(account << 1 | u32::from(authentication_server)),
+        ))
+    }
+
+    /// The `const` version of [`Id64::new_full`], which returns `None` if the values passed don't
+    /// fit their fields of the steam id 64 or a known universe or account type is passed as
+    /// `Unknown`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use steamidfx::id::{AccountType, Id64, Universe};
+    ///
+    /// const ID: Option<Id64> =
+    ///     Id64::checked_new_full(Universe::Public, AccountType::Individual, 1, 0, 11526534);
+    /// assert_eq!(ID, Some(Id64(76561197983318796)));
+    /// ```
+    #[must_use]
+    pub const fn checked_new_full(
+        universe: Universe,
+        account_type: AccountType,
+        account_instance: u32,
+        authentication_server: u8,
+        account: u32,
+    ) -> Option<Id64> {
+        if !universe.is_canonical()
+            || !account_type.is_canonical()
+            || account_type.to_u8() > MAX_ACCOUNT_TYPE
+            || account_instance > MAX_INSTANCE
+        {
+            return None;
+        }
+        Some(Id64::from_parts(
+            universe,
+            account_type,
+            account_instance,
+            const_try!(AccountId::new(account, authentication_server)),
+        ))
+    }
+
+    /// The `const` version of [`Id64::new_simple`], which returns `None` if the values passed
+    /// don't fit their fields of the steam id 64.
+    #[must_use]
+    pub const fn checked_new_simple(
+        universe: Universe,
+        authentication_server: u8,
+        account: u32,
+    ) -> Option<Id64> {
+        Id64::checked_new_full(
+            universe,
+            AccountType::Individual,
+            AccountType::Individual.default_instance(),
+            authentication_server,
+            account,
+        )
+    }
+
+    /// The `const` version of [`Id64::parse_bytes`], which returns `None` if the bytes don't
+    /// contain a steam id. Used by the [`steamid!`](crate::steamid) macro.
+    #[must_use]
+    pub const fn checked_parse(value: &[u8]) -> Option<Id64> {
+        crate::parse::parse_id64(value)
+    }
+
+    /// Returns the id of the chat room of this clan.
+    ///
+    /// # Errors
+    /// Returns an error if the id is incorrect or doesn't belong to a clan.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// let clan = steamidfx::id::Id64(103582791429521412);
+    /// let chat = clan.clan_chat_room().unwrap();
+    /// assert_eq!(chat, steamidfx::id::Id64(110338190870577156));
+    /// assert_eq!(chat.chat_room_clan().unwrap(), clan);
+    /// ```
+    pub fn clan_chat_room(self) -> crate::error::Result<Id64> {
+        let info = self.info()?;
+        if info.account_type != AccountType::Clan {
+            return Err(crate::error::Error::unsupported_account_type(
+                info.account_type,
+                "a clan chat room",
+            ));
+        }
+        Id64::new_full(
+            info.universe,
+            AccountType::Chat,
+            ChatInstanceFlags::CLAN.0,
+            info.authentication_server,
+            info.account,
+        )
+    }
+
+    /// Returns the id of the clan this clan chat room belongs to.
+    ///
+    /// # Errors
+    /// Returns an error if the id is incorrect or isn't a clan chat room.
+    pub fn chat_room_clan(self) -> crate::error::Result<Id64> {
+        let info = self.info()?;
+        match info.chat_instance_flags() {
+            Some(flags) if flags.contains(ChatInstanceFlags::CLAN) => Id64::new_full(
+                info.universe,
+                AccountType::Clan,
+                0,
+                info.authentication_server,
+                info.account,
             ),
-            2,
-        )?;
-        Ok(Id64(num))
+            _ => Err(crate::error::Error::unsupported_account_type(
+                info.account_type,
+                "a clan",
+            )),
+        }
     }
 }
 
@@ -381,8 +1147,106 @@ impl Id64 {
 )]
 pub struct Id32(pub String);
 
+impl Id32 {
+    /// Get a detailed information about the steam account from the steam id.
+    /// The steam id 32 always belongs to an individual account of the desktop instance.
+    ///
+    /// # Errors
+    /// Returns an error if the id is of an incorrect format.
+    pub fn info(&self) -> crate::error::Result<Info> {
+        Id64::try_from(self.clone())?.info()
+    }
+
+    /// Converts the steam id 64 into the steam id 32, writing the universe digit according to the
+    /// policy passed. Both `STEAM_0` and `STEAM_1` are parsed as the public universe, regardless
+    /// of the policy, so ids written using different policies still compare the same using
+    /// [`Id::is_same`].
+    ///
+    /// # Errors
+    /// Returns an error if the id is incorrect, doesn't belong to an individual account of the
+    /// desktop instance or its universe is invalid or unknown.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use steamidfx::id::{Engine, Id32, Id32UniversePolicy, Id64};
+    ///
+    /// let id = Id64(76561197983318796);
+    /// assert_eq!(
+    ///     Id32::with_universe_policy(id, Id32UniversePolicy::LegacyZero).unwrap(),
+    ///     Id32("STEAM_0:0:11526534".to_owned())
+    /// );
+    /// assert_eq!(
+    ///     Id32::with_universe_policy(id, Engine::OrangeBox.into()).unwrap(),
+    ///     Id32("STEAM_1:0:11526534".to_owned())
+    /// );
+    /// ```
+    pub fn with_universe_policy(
+        id: Id64,
+        policy: Id32UniversePolicy,
+    ) -> crate::error::Result<Id32> {
+        Ok(Id32(Id32Fields::from_id64(id, policy)?.to_string()))
+    }
+
+    /// Rewrites this steam id 32 using the universe digit policy passed, so that the ids coming
+    /// from different games can be stored the same way.
+    ///
+    /// # Errors
+    /// Returns an error if the id is incorrect.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use steamidfx::id::{Id32, Id32UniversePolicy};
+    ///
+    /// assert_eq!(
+    ///     Id32("STEAM_1:0:11526534".to_owned()).normalize(Id32UniversePolicy::LegacyZero).unwrap(),
+    ///     Id32("STEAM_0:0:11526534".to_owned())
+    /// );
+    /// ```
+    pub fn normalize(&self, policy: Id32UniversePolicy) -> crate::error::Result<Id32> {
+        Id32::with_universe_policy(Id64::try_from(self.clone())?, policy)
+    }
+}
+
+/// The policy of writing the universe digit (the `X` in `STEAM_X:Y:Z`) of a steam id 32.
+///
+/// According to the specification, it is the universe number, but the older games always
+/// write `0` there for the public universe, and so do most of the tools.
+#[derive(Debug, Copy, Clone, Default, Ord, PartialOrd, Hash, PartialEq, Eq)]
+pub enum Id32UniversePolicy {
+    /// Write `0` for the public universe and the universe number for all the others.
+    /// This is the default.
+    #[default]
+    LegacyZero,
+    /// Always write the universe number, so `1` for the public universe.
+    RealUniverse,
+}
+
+/// The game engines, which print the steam id 32 differently.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Hash, PartialEq, Eq)]
+pub enum Engine {
+    /// The `GoldSrc` games: Half-Life, Counter-Strike 1.6 and others.
+    GoldSrc,
+    /// The Source games released before the Orange Box: Counter-Strike: Source, Half-Life 2 and
+    /// others.
+    Source,
+    /// The Orange Box and the later Source games: Team Fortress 2, CS:GO, Garry's Mod, Left 4 Dead
+    /// and others.
+    OrangeBox,
+}
+
+impl From<Engine> for Id32UniversePolicy {
+    fn from(engine: Engine) -> Id32UniversePolicy {
+        match engine {
+            Engine::GoldSrc | Engine::Source => Id32UniversePolicy::LegacyZero,
+            Engine::OrangeBox => Id32UniversePolicy::RealUniverse,
+        }
+    }
+}
+
 /// Steam Id 3.
-/// Example: `U:1:xxxxxxxx`.
+/// Example: `U:1:xxxxxxxx`, `[U:1:xxxxxxxx]` or `[A:1:xxxxxxxx:xxxx]`.
 #[allow(clippy::module_name_repetitions)]
 #[derive(Debug, Clone, Ord, PartialOrd, Hash, PartialEq, Eq)]
 #[cfg_attr(
@@ -392,28 +1256,59 @@ pub struct Id32(pub String);
 pub struct Id3(pub String);
 impl Id3 {
     /// Get a detailed information about the steam account from the steam id.
-    /// This information may not contain all the fields correct as to how `SteamId64` can due to unavailable
-    /// documentation of this format.
+    /// The middle number is the universe, and the last one is the account id, which consists
+    /// of the account number and the authentication server bit, exactly as in the steam id 64.
+    /// When the instance isn't written, the one implied by the account type is used.
     ///
     /// # Errors
-    /// Returns an error if the id is of an incorrect format.
+    /// Returns an error if the id is of an incorrect format or the universe is incorrect.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use steamidfx::id::{Id3, Id64};
+    ///
+    /// assert_eq!(
+    ///     Id3("[U:1:23053069]".to_owned()).info().unwrap(),
+    ///     Id64(76561197983318797).info().unwrap()
+    /// );
+    /// ```
     pub fn info(&self) -> crate::error::Result<Info> {
-        let split: Vec<&str> = self.0.split(':').collect();
-        if split.len() < 3 {
-            return Err(crate::error::ErrorKind::InvalidSteamId(self.0.clone()).into());
-        }
-        let authentication_server: u8 = split[1].parse()?;
-        let account: u32 = split[2].parse()?;
+        let fields = Id3Fields::parse(&self.0)?;
         Ok(Info {
-            // The universe is hard to know for sure, as from `SteamId3` format it is unknown how to
-            // parse it.
-            universe: Universe::IndividualOrUnspecified,
-            account_type: AccountType::from_str(split[0])?,
-            instance: u32::from(DEFAULT_STEAM_ACCOUNT_INSTANCE),
-            account,
-            authentication_server,
+            universe: Universe::from_bits(u64::from(fields.universe)).ok_or(
+                crate::error::Error::OutOfRange {
+                    field: Field::Universe,
+                    value: u64::from(fields.universe),
+                },
+            )?,
+            account_type: fields.account_type,
+            instance: fields.instance(),
+            account: fields.account_id.account(),
+            authentication_server: fields.account_id.authentication_server(),
         })
     }
+
+    /// Renders the id the way valve's `CSteamID::Render` does: always in square brackets and with
+    /// the instance written only when it is meaningful for the account type or differs from the
+    /// one the account type implies.
+    ///
+    /// # Errors
+    /// Returns an error if the id is of an incorrect format.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use steamidfx::id::Id3;
+    ///
+    /// assert_eq!(Id3("U:1:23053068".to_owned()).render().unwrap(), Id3("[U:1:23053068]".to_owned()));
+    /// assert_eq!(Id3("U:1:23053068:1".to_owned()).render().unwrap(), Id3("[U:1:23053068]".to_owned()));
+    /// assert_eq!(Id3("A:1:123".to_owned()).render().unwrap(), Id3("[A:1:123:0]".to_owned()));
+    /// assert_eq!(Id3("[A:1:123:4567]".to_owned()).render().unwrap(), Id3("[A:1:123:4567]".to_owned()));
+    /// ```
+    pub fn render(&self) -> crate::error::Result<Id3> {
+        Ok(Id3(Id3Fields::parse(&self.0)?.render()))
+    }
 }
 
 /// <https://developer.valvesoftware.com/wiki/SteamID>
@@ -451,67 +1346,62 @@ impl Id3 {
 /// // This way you'll make sure after unpacking the `Result` that the value is correct
 /// // at least, according to the specification.
 /// ```
-
 #[allow(clippy::module_name_repetitions)]
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub enum Id {
-    /// Steam ID in a single integer format (SteamID64).
+    /// Steam ID in a single integer format (`SteamID64`).
     /// Example: `7656119xxxxxxxxxx`.
     Id64(Id64),
     /// Steam ID 32 in the default format, starting with `STEAM_0`.
     /// Example: `STEAM_0:X:XXXXXXXX`.
     Id32(Id32),
     /// Steam ID in the format called "Steam ID 3".
-    /// Example: `U:1:xxxxxxxx`.
+    /// Example: `U:1:xxxxxxxx` or `[U:1:xxxxxxxx]`.
     Id3(Id3),
 }
 
-impl std::convert::TryFrom<u64> for Id {
+impl core::convert::TryFrom<u64> for Id {
     type Error = crate::error::Error;
 
     fn try_from(id: u64) -> crate::error::Result<Id> {
         let id = Id64(id);
-        if let Err(e) = id.info() {
-            return Err(e);
+        if !id.universe().is_known() {
+            return Err(crate::error::Error::OutOfRange {
+                field: Field::Universe,
+                value: id.0 >> 56,
+            });
+        }
+        if !id.account_type().is_known() {
+            return Err(crate::error::Error::OutOfRange {
+                field: Field::AccountType,
+                value: id.0 >> 52 & 0xF,
+            });
         }
         Ok(Id::Id64(id))
     }
 }
 
-impl std::convert::TryFrom<Id32> for Id3 {
+impl core::convert::TryFrom<Id32> for Id3 {
     type Error = crate::error::Error;
 
     fn try_from(id: Id32) -> crate::error::Result<Id3> {
-        if ID32_REGEXP.is_match(&id.0) {
-            let split: Vec<&str> = id.0.split(':').collect();
-            let first: u64 = split[1].parse()?;
-            let second: u64 = split[2].parse()?;
-            let num = second * 2 + first;
-            return Ok(Id3(format!("U:1:{}", num)));
-        }
-        Err("The steam id provided is not in the SteamID32 format.".into())
+        Id3::try_from(Id64::try_from(id)?)
     }
 }
 
-impl std::convert::TryFrom<Id3> for Id32 {
+impl core::convert::TryFrom<Id3> for Id32 {
     type Error = crate::error::Error;
 
     fn try_from(id: Id3) -> crate::error::Result<Id32> {
-        if let Some(captures) = ID3_REGEXP.captures(&id.0) {
-            if captures.len() < 4 {
-                return Err("The steam id provided is not in the SteamID3 format.".into());
-            }
-            let _account_type = AccountType::from_str(captures.get(1).unwrap().as_str())?;
-            // Probably this is not an authentication server, but I don't know then what it can be.
-            let _authentication_server: u8 = captures.get(2).unwrap().as_str().parse()?;
-            let account: u32 = captures.get(3).unwrap().as_str().parse()?;
-            if account % 2 == 0 {
-                return Ok(Id32(format!("STEAM_0:0:{}", account / 2)));
-            }
+        Id32::try_from(Id64::try_from(id)?)
+    }
+}
 
-            return Ok(Id32(format!("STEAM_0:1:{}", (account - 1) / 2)));
-        }
-        Err("The steam id provided is not in the SteamID3 format.".into())
+impl TryFrom<Id64> for Id3 {
+    type Error = crate::error::Error;
+
+    fn try_from(id: Id64) -> crate::error::Result<Id3> {
+        Ok(Id3(Id3Fields::from_info(&id.info()?)?.render()))
     }
 }
 
@@ -519,16 +1409,38 @@ impl TryFrom<Id64> for Id32 {
     type Error = crate::error::Error;
 
     fn try_from(id: Id64) -> crate::error::Result<Id32> {
-        // Here we go off-spec as it seems they have implemented it wrong.
-        // The first digit after the `"STEAM_"` should be the universe number, but it
-        // is just either always zero or is not a universe number.
-        // Hence it is hardcoded to be 0 when we convert the `SteamId64` to `SteamId32`.
-        // It works, but off-spec.
-        let info = id.info()?;
-        Ok(Id32(format!(
-            "STEAM_0:{}:{}",
-            info.authentication_server, info.account
-        )))
+        Id32::with_universe_policy(id, Id32UniversePolicy::default())
+    }
+}
+
+/// Returns an error if the value doesn't fit the field.
+fn check_range<T: Into<u64>>(field: Field, value: T, max: T) -> crate::error::Result<()> {
+    let value = value.into();
+    if value > max.into() {
+        return Err(crate::error::Error::OutOfRange { field, value });
+    }
+    Ok(())
+}
+
+/// Checks the field isn't a known value passed as `Unknown`.
+fn check_canonical(field: Field, canonical: bool, value: u8) -> crate::error::Result<()> {
+    if !canonical {
+        return Err(crate::error::Error::KnownAsUnknown { field, value });
+    }
+    Ok(())
+}
+
+/// Returns the digit written after `STEAM_` for the universe.
+fn id32_universe_digit(universe: Universe, policy: Id32UniversePolicy) -> crate::error::Result<u8> {
+    match (universe, policy) {
+        (Universe::Invalid | Universe::Unknown(_), _) => {
+            Err(crate::error::Error::UnsupportedUniverse {
+                universe,
+                target: "a steam id 32",
+            })
+        }
+        (Universe::Public, Id32UniversePolicy::LegacyZero) => Ok(0),
+        (universe, _) => Ok(universe.to_u8()),
     }
 }
 
@@ -536,23 +1448,12 @@ impl TryFrom<Id32> for Id64 {
     type Error = crate::error::Error;
 
     fn try_from(id: Id32) -> crate::error::Result<Id64> {
-        if let Some(captures) = ID32_REGEXP.captures(&id.0) {
-            if captures.len() < 4 {
-                return Err("The steam id provided is not in the SteamID32 format.".into());
-            }
-            let mut universe: u8 = captures.get(1).unwrap().as_str().parse()?;
-            let authentication_server: u8 = captures.get(2).unwrap().as_str().parse()?;
-            let account: u32 = captures.get(3).unwrap().as_str().parse()?;
-            if universe == 0 {
-                universe = 1;
-            }
-            return Id64::new_simple(
-                Universe::try_from(universe)?,
-                authentication_server,
-                account,
-            );
-        }
-        Err("The steam id provided is not in the SteamID32 format.".into())
+        let fields = Id32Fields::parse(&id.0)?;
+        Id64::new_simple(
+            fields.universe()?,
+            fields.authentication_server,
+            fields.account,
+        )
     }
 }
 
@@ -560,11 +1461,48 @@ impl TryFrom<Id3> for Id64 {
     type Error = crate::error::Error;
 
     fn try_from(id: Id3) -> crate::error::Result<Id64> {
-        Id64::try_from(Id32::try_from(id)?)
+        let info = id.info()?;
+        if info.account_type == AccountType::Invalid {
+            return Err(crate::error::Error::unsupported_account_type(
+                info.account_type,
+                "a steam id 64",
+            ));
+        }
+        Id64::new_full(
+            info.universe,
+            info.account_type,
+            info.instance,
+            info.authentication_server,
+            info.account,
+        )
     }
 }
 
 impl Id {
+    /// Get a detailed information about the steam account from the steam id of any format.
+    /// The information is the same for the same account, whatever the format is.
+    ///
+    /// # Errors
+    /// Returns an error if the id is of an incorrect format.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::str::FromStr;
+    /// use steamidfx::id::Id;
+    ///
+    /// let info = Id::from_str("76561197983318796").unwrap().info().unwrap();
+    /// assert_eq!(Id::from_str("STEAM_0:0:11526534").unwrap().info().unwrap(), info);
+    /// assert_eq!(Id::from_str("[U:1:23053068]").unwrap().info().unwrap(), info);
+    /// ```
+    pub fn info(&self) -> crate::error::Result<Info> {
+        match self {
+            Id::Id64(num) => num.info(),
+            Id::Id32(id) => id.info(),
+            Id::Id3(id) => id.info(),
+        }
+    }
+
     /// Converts (if needed) the current id format into id64.
     ///
     /// # Errors
@@ -589,6 +1527,18 @@ impl Id {
         }
     }
 
+    /// Converts (if needed) the current id format into id3.
+    ///
+    /// # Errors
+    /// Throws `crate::error::Error` if it was impossible to extract the steam id 3.
+    pub fn id3(&self) -> crate::error::Result<Id3> {
+        match self {
+            Id::Id64(num) => Id3::try_from(*num),
+            Id::Id32(id) => Id3::try_from(id.clone()),
+            Id::Id3(id) => Ok(id.clone()),
+        }
+    }
+
     /// Consumes the object and converts it into a steam id in the id64 format.
     ///
     /// # Errors
@@ -605,6 +1555,14 @@ impl Id {
         Ok(Id::Id32(self.id32()?))
     }
 
+    /// Consumes the object and converts it into a steam id in the id3 format.
+    ///
+    /// # Errors
+    /// Throws `crate::error::Error` if it was impossible to extract the steam id 3.
+    pub fn into_id3(self) -> crate::error::Result<Id> {
+        Ok(Id::Id3(self.id3()?))
+    }
+
     /// Attempts to compare two ids. Returns `true` when they are representing
     /// the same values, even using different formats.
     /// The conventional `Eq` and `PartialEq` traits derived will be checking
@@ -630,43 +1588,33 @@ impl Id {
     /// let id_3 = steamidfx::id::Id::from_str("U:1:23053068").unwrap();
     /// assert!(id_3.is_same(&id_32).unwrap());
     /// assert!(id_32.is_same(&id_64).unwrap());
+    ///
+    /// // The universe digit of the steam id 32 is written differently by different games.
+    /// let id_32_orange_box = steamidfx::id::Id::from_str("STEAM_1:0:11526534").unwrap();
+    /// assert!(id_32_orange_box.is_same(&id_32).unwrap());
     /// ```
     pub fn is_same(&self, other: &Id) -> crate::error::Result<bool> {
         Ok(self.id64()? == other.id64()?)
     }
 }
 
-impl std::str::FromStr for Id {
+impl core::str::FromStr for Id {
     type Err = crate::error::Error;
 
-    fn from_str(value: &str) -> std::result::Result<Self, Self::Err> {
+    fn from_str(value: &str) -> core::result::Result<Self, Self::Err> {
         if let Ok(id64) = value.parse::<u64>() {
             return Ok(Id::Id64(Id64(id64)));
         }
 
-        if ID32_REGEXP.is_match(value) {
+        if Id32Fields::parse_bytes(value.as_bytes()).is_some() {
             return Ok(Id::Id32(Id32(value.to_owned())));
         }
 
-        if ID3_REGEXP.is_match(value) {
+        if Id3Fields::parse_bytes(value.as_bytes()).is_some() {
             return Ok(Id::Id3(Id3(value.to_owned())));
         }
 
-        Err(crate::error::ErrorKind::InvalidSteamId(format!(
-            "Not a valid steam id value: {}",
-            value
-        ))
-        .into())
-    }
-}
-
-impl std::fmt::Display for Id {
-    fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
-        match self {
-            Id::Id64(num) => fmt.write_str(&num.0.to_string()),
-            Id::Id32(s) => fmt.write_str(&s.0),
-            Id::Id3(s) => fmt.write_str(&s.0),
-        }
+        Err(crate::error::Error::invalid_format("steam id", value))
     }
 }
 
@@ -679,11 +1627,7 @@ impl serde::Serialize for Id {
     where
         S: serde::Serializer,
     {
-        serializer.serialize_u64(
-            self.id64()
-                .map_err(|e| serde::ser::Error::custom(e.description()))?
-                .0,
-        )
+        serializer.serialize_u64(self.id64().map_err(serde::ser::Error::custom)?.0)
     }
 }
 
@@ -691,21 +1635,21 @@ impl serde::Serialize for Id {
 struct IdVisitor;
 
 #[cfg(feature = "serialization")]
-impl<'de> Visitor<'de> for IdVisitor {
+impl Visitor<'_> for IdVisitor {
     type Value = Id;
 
-    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
         formatter.write_str("a steam id in the format of  ID64,  ID, or  ID 3.")
     }
 
-    fn visit_u64<E>(self, value: u64) -> std::result::Result<Self::Value, E>
+    fn visit_u64<E>(self, value: u64) -> core::result::Result<Self::Value, E>
     where
         E: de::Error,
     {
         Ok(Id::Id64(Id64(value)))
     }
 
-    fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+    fn visit_str<E>(self, value: &str) -> core::result::Result<Self::Value, E>
     where
         E: de::Error,
     {
@@ -714,7 +1658,7 @@ impl<'de> Visitor<'de> for IdVisitor {
 }
 #[cfg(feature = "serialization")]
 impl<'de> serde::Deserialize<'de> for Id {
-    fn deserialize<D>(deserializer: D) -> std::result::Result<Id, D::Error>
+    fn deserialize<D>(deserializer: D) -> core::result::Result<Id, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
@@ -725,6 +1669,7 @@ impl<'de> serde::Deserialize<'de> for Id {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use core::str::FromStr;
 
     #[cfg(feature = "serialization")]
     #[allow(clippy::unreadable_literal)]
@@ -761,4 +1706,396 @@ mod tests {
         let id3 = serde_json::from_str::<Id>(strid3).unwrap();
         assert_eq!(id3, Id::Id3(Id3("U:1:23053068".to_owned())));
     }
+
+    #[cfg(feature = "serialization")]
+    #[test]
+    fn renamed_variants_deserialize() {
+        let universe = serde_json::from_str::<Universe>("\"IndividualOrUnspecified\"").unwrap();
+        assert_eq!(universe, Universe::Invalid);
+        let account_type = serde_json::from_str::<AccountType>("\"PeerToPeerSuperSeeder\"");
+        assert_eq!(account_type.unwrap(), AccountType::ConsoleUser);
+        assert_eq!(
+            serde_json::to_string(&AccountType::Unknown(11)).unwrap(),
+            "{\"Unknown\":11}"
+        );
+    }
+
+    #[cfg(feature = "serialization")]
+    #[test]
+    fn unknown_variant_names_deserialize() {
+        let account_type = serde_json::from_str::<AccountType>("\"Foo\"").unwrap();
+        assert_eq!(account_type, AccountType::Invalid);
+        let account_type = serde_json::from_str::<AccountType>("{\"Unknown\":11}").unwrap();
+        assert_eq!(account_type, AccountType::Unknown(11));
+        let account_type = serde_json::from_str::<AccountType>("\"Clan\"").unwrap();
+        assert_eq!(account_type, AccountType::Clan);
+    }
+
+    #[allow(clippy::unreadable_literal)]
+    #[test]
+    fn steam_id_3_bracketed_and_instance() {
+        let bare = Id::from_str("U:1:23053068").unwrap();
+        let bracketed = Id::from_str("[U:1:23053068]").unwrap();
+        assert_eq!(bracketed, Id::Id3(Id3("[U:1:23053068]".to_owned())));
+        assert!(bare.is_same(&bracketed).unwrap());
+        assert_eq!(bracketed.id64().unwrap(), Id64(76561197983318796));
+        assert_eq!(
+            bracketed.id32().unwrap(),
+            Id32("STEAM_0:0:11526534".to_owned())
+        );
+
+        let server = Id3("[A:1:123:4567]".to_owned());
+        assert_eq!(server.info().unwrap().instance, 4567);
+        assert_eq!(server.info().unwrap().account, 61);
+        assert_eq!(server.info().unwrap().authentication_server, 1);
+        assert!(Id::from_str("A:1:123:4567").is_ok());
+
+        assert!(Id::from_str("[U:1:23053068").is_err());
+        assert!(Id::from_str("U:1:23053068]").is_err());
+        assert!(Id::from_str("[X:1:23053068]").is_err());
+        assert!(Id::from_str("[U:1:23053068:]").is_err());
+    }
+
+    #[allow(clippy::unreadable_literal)]
+    #[test]
+    fn steam_id_3_chat_letters() {
+        for (id3, flags) in &[
+            ("[c:1:4]", ChatInstanceFlags::CLAN),
+            ("[L:1:4]", ChatInstanceFlags::LOBBY),
+            ("[T:1:4]", ChatInstanceFlags::default()),
+        ] {
+            let id3 = Id3((*id3).to_owned());
+            assert_eq!(id3.info().unwrap().chat_instance_flags(), Some(*flags));
+            assert_eq!(id3.render().unwrap(), id3);
+        }
+        let lobby = ChatInstanceFlags::LOBBY | ChatInstanceFlags::MMS_LOBBY;
+        assert!(lobby.contains(ChatInstanceFlags::MMS_LOBBY));
+        assert!(!lobby.contains(ChatInstanceFlags::CLAN));
+        assert_eq!(
+            Id3("T:1:4:262144".to_owned()).render().unwrap(),
+            Id3("[L:1:4]".to_owned())
+        );
+        assert!(Id64(76561197983318796).clan_chat_room().is_err());
+        assert!(Id64(103582791429521412).chat_room_clan().is_err());
+    }
+
+    #[allow(clippy::unreadable_literal)]
+    #[test]
+    fn steam_id_3_to_64_keeps_account_type() {
+        let pairs = [
+            ("[U:1:23053068]", 76561197983318796u64),
+            ("U:1:23053069", 76561197983318797),
+            ("[g:1:4]", 103582791429521412),
+            ("[c:1:4]", 110338190870577156),
+            ("[G:1:5]", 85568392920039429),
+            ("[A:1:123:4567]", 90091607663050875),
+            ("[U:2:23053068]", 148618792021246732),
+        ];
+        for (id3, id64) in &pairs {
+            assert_eq!(Id64::try_from(Id3((*id3).to_owned())).unwrap(), Id64(*id64));
+        }
+        assert!(Id64::try_from(Id3("[I:1:4]".to_owned())).is_err());
+        assert!(Id64::try_from(Id3("[U:9:4]".to_owned())).is_err());
+    }
+
+    #[allow(clippy::unreadable_literal)]
+    const CONVERSION_IDS: [u64; 11] = [
+        76561197983318796,  // [U:1:23053068]
+        76561197983318797,  // [U:1:23053069]
+        148618792021246732, // [U:2:23053068]
+        76565596029829900,  // [U:1:23053068:1025]
+        103582791429521412, // [g:1:4]
+        110338190870577156, // [c:1:4]
+        109212290963734532, // [L:1:4]
+        109775240917155844, // [L:1:4:393216]
+        85568392920039429,  // [G:1:5]
+        90091607663050875,  // [A:1:123:4567]
+        4503603945390860,   // [U:0:23053068]
+    ];
+
+    #[test]
+    fn conversions_round_trip() {
+        for id64 in CONVERSION_IDS.iter().map(|id| Id64(*id)) {
+            let id3 = Id3::try_from(id64).unwrap();
+            assert_eq!(Id64::try_from(id3.clone()).unwrap(), id64, "{id3:?}");
+            match Id32::try_from(id64) {
+                Ok(id32) => {
+                    assert_eq!(Id64::try_from(id32.clone()).unwrap(), id64);
+                    assert_eq!(Id3::try_from(id32.clone()).unwrap(), id3);
+                    assert_eq!(Id32::try_from(id3).unwrap(), id32);
+                }
+                Err(_) => assert!(Id32::try_from(id3).is_err()),
+            }
+        }
+    }
+
+    #[allow(clippy::unreadable_literal)]
+    #[test]
+    fn conversions_through_id() {
+        let id = Id::Id64(Id64(76561197983318796));
+        assert_eq!(id.id3().unwrap(), Id3("[U:1:23053068]".to_owned()));
+        assert_eq!(
+            id.clone().into_id32().unwrap().into_id3().unwrap(),
+            Id::Id3(Id3("[U:1:23053068]".to_owned()))
+        );
+        assert_eq!(
+            Id::Id3(Id3("U:1:23053068".to_owned())).id3().unwrap(),
+            Id3("U:1:23053068".to_owned())
+        );
+        assert_eq!(
+            Id32::try_from(Id64(148618792021246732)).unwrap(),
+            Id32("STEAM_2:0:11526534".to_owned())
+        );
+        assert!(Id32::try_from(Id64(103582791429521412)).is_err());
+        assert!(Id32::try_from(Id64(90091607663050875)).is_err());
+        assert!(Id3::try_from(Id64(126100789566373889)).is_err());
+    }
+
+    #[allow(clippy::unreadable_literal)]
+    #[test]
+    fn clans() {
+        let clan = Id64::new_clan(Universe::Public, 33_428_787).unwrap();
+        assert_eq!(clan, Id64(103582791462950195));
+        assert!(clan.is_clan());
+        assert!(!Id64(76561197983318796).is_clan());
+        assert!(!clan.clan_chat_room().unwrap().is_clan());
+        assert_eq!(
+            Id::from_str("[g:1:33428787]").unwrap().id64().unwrap(),
+            clan
+        );
+        assert_eq!(
+            Id3::try_from(clan).unwrap(),
+            Id3("[g:1:33428787]".to_owned())
+        );
+        assert_eq!(clan.info().unwrap().instance, 0);
+        assert!(Id::Id64(clan).id32().is_err());
+    }
+
+    #[allow(clippy::unreadable_literal)]
+    #[test]
+    fn game_servers() {
+        let server = Id64::new_game_server(Universe::Public, 3771155, 2).unwrap();
+        assert!(server.is_game_server());
+        assert!(!server.is_anonymous_game_server());
+        assert_eq!(
+            Id3::try_from(server).unwrap(),
+            Id3("[G:1:3771155:2]".to_owned())
+        );
+        for instance in &[0, 1, 4567, 0xF_FFFF] {
+            let server = Id64::new_anonymous_game_server(Universe::Public, 123, *instance).unwrap();
+            let id3 = Id3::try_from(server).unwrap();
+            assert_eq!(id3, Id3(format!("[A:1:123:{instance}]")));
+            assert_eq!(Id64::try_from(id3).unwrap(), server);
+            assert!(Id32::try_from(server).is_err());
+        }
+        assert_eq!(
+            Id::from_str("[A:1:123]").unwrap().id64().unwrap(),
+            Id64::new_anonymous_game_server(Universe::Public, 123, 0).unwrap()
+        );
+        assert!(!Id64(76561197983318796).is_game_server());
+    }
+
+    #[allow(clippy::unreadable_literal)]
+    #[test]
+    fn id32_universe_policy() {
+        let beta = Id64(148618792021246732);
+        for policy in &[
+            Id32UniversePolicy::LegacyZero,
+            Id32UniversePolicy::RealUniverse,
+        ] {
+            let id32 = Id32::with_universe_policy(beta, *policy).unwrap();
+            assert_eq!(id32, Id32("STEAM_2:0:11526534".to_owned()));
+            assert_eq!(Id64::try_from(id32).unwrap(), beta);
+        }
+        assert_eq!(
+            Id32UniversePolicy::from(Engine::GoldSrc),
+            Id32UniversePolicy::default()
+        );
+        let legacy = Id::from_str("STEAM_0:1:11526534").unwrap();
+        let orange_box = Id::from_str("STEAM_1:1:11526534").unwrap();
+        assert!(legacy.is_same(&orange_box).unwrap());
+        assert!(!legacy
+            .is_same(&Id::from_str("STEAM_1:0:11526534").unwrap())
+            .unwrap());
+        assert!(Id32::with_universe_policy(Id64(4503603945390860), Engine::Source.into()).is_err());
+    }
+
+    #[allow(clippy::unreadable_literal)]
+    #[test]
+    fn info_is_the_same_for_all_formats() {
+        for id64 in CONVERSION_IDS.iter().map(|id| Id64(*id)) {
+            let info = id64.info().unwrap();
+            assert_eq!(Id3::try_from(id64).unwrap().info().unwrap(), info);
+            if let Ok(id32) = Id32::try_from(id64) {
+                assert_eq!(id32.info().unwrap(), info);
+            }
+        }
+        let info = Id3("[U:1:23053069]".to_owned()).info().unwrap();
+        assert_eq!(info.universe, Universe::Public);
+        assert_eq!(info.account, 11526534);
+        assert_eq!(info.authentication_server, 1);
+        assert!(Id3("[U:7:23053069]".to_owned()).info().is_err());
+    }
+
+    #[allow(clippy::unreadable_literal)]
+    #[test]
+    fn const_accessors_are_the_same_as_info() {
+        for id64 in CONVERSION_IDS.iter().map(|id| Id64(*id)) {
+            let info = id64.info().unwrap();
+            assert_eq!(id64.universe(), info.universe);
+            assert_eq!(id64.account_type(), info.account_type);
+            assert_eq!(id64.instance(), info.instance);
+            assert_eq!(id64.account(), info.account);
+            assert_eq!(id64.authentication_server(), info.authentication_server);
+            let checked = Id64::checked_new_full(
+                info.universe,
+                info.account_type,
+                info.instance,
+                info.authentication_server,
+                info.account,
+            );
+            assert_eq!(checked, Some(id64));
+        }
+        assert_eq!(Id64(0xFF << 56).universe(), Universe::Unknown(0xFF));
+        assert_eq!(Id64(0xF << 52).account_type(), AccountType::Unknown(0xF));
+    }
+
+    #[allow(clippy::unreadable_literal)]
+    #[test]
+    fn const_constructors() {
+        const ID: Id64 = crate::steamid!("STEAM_0:1:11526534");
+        const CLAN: Id64 = crate::steamid!("[g:1:4]");
+        assert_eq!(ID, Id64(76561197983318797));
+        assert_eq!(CLAN, Id64::new_clan(Universe::Public, 4).unwrap());
+        assert_eq!(
+            Id64::checked_new_simple(Universe::Public, 1, 11526534),
+            Some(ID)
+        );
+        assert_eq!(Id64::checked_new_simple(Universe::Public, 2, 1), None);
+        assert_eq!(
+            Id64::checked_new_simple(Universe::Public, 0, MAX_ACCOUNT + 1),
+            None
+        );
+        let instance = MAX_INSTANCE + 1;
+        assert_eq!(
+            Id64::checked_new_full(Universe::Public, AccountType::Clan, instance, 0, 4),
+            None
+        );
+        assert_eq!(Id64::checked_parse(b"[U:1:23053069]"), Some(ID));
+        assert_eq!(Id64::checked_parse(b"STEAM_0:1:"), None);
+    }
+
+    #[allow(clippy::unreadable_literal)]
+    #[allow(clippy::too_many_lines)]
+    #[test]
+    fn unknown_universes_and_account_types() {
+        for value in 0..=u8::MAX {
+            assert_eq!(Universe::from_u8(value).to_u8(), value);
+            assert_eq!(AccountType::from_u8(value).to_u8(), value);
+        }
+        assert_eq!(AccountType::from_u8(9), AccountType::ConsoleUser);
+        assert!(AccountType::Invalid < AccountType::Individual);
+        assert!(AccountType::AnonymousUser < AccountType::Unknown(11));
+        assert!(Universe::Invalid < Universe::Public);
+        let id = Id64(76561197983318796)
+            .with_universe(Universe::from_u8(7))
+            .and_then(|id| id.with_account_type(AccountType::from_u8(11)))
+            .unwrap();
+        let info = id.info().unwrap();
+        assert_eq!(info.universe, Universe::Unknown(7));
+        assert_eq!(info.account_type, AccountType::Unknown(11));
+        assert_eq!(Id64::try_from(info).unwrap(), id);
+        assert_eq!(info.universe.to_string(), "Unknown (7)");
+        assert!(Id::try_from(id.0).is_err());
+        assert!(Id3::try_from(id).is_err());
+        let public = id.with_account_type(AccountType::Individual).unwrap();
+        assert!(Id3::try_from(public).is_err());
+        assert!(Id32::try_from(public).is_err());
+    }
+
+    #[allow(clippy::too_many_lines)]
+    #[test]
+    fn new_full_round_trips_the_field_boundaries() {
+        for universe in (0..=u8::MAX).map(Universe::from_u8) {
+            for account_type in (0..=MAX_ACCOUNT_TYPE).map(AccountType::from_u8) {
+                for &instance in &[0, 1, MAX_INSTANCE - 1, MAX_INSTANCE] {
+                    for &account in &[0, 1, MAX_ACCOUNT - 1, MAX_ACCOUNT] {
+                        for authentication_server in 0..=1 {
+                            let info = Info {
+                                universe,
+                                account_type,
+                                instance,
+                                account,
+                                authentication_server,
+                            };
+                            let id = Id64::new_full(
+                                universe,
+                                account_type,
+                                instance,
+                                authentication_server,
+                                account,
+                            )
+                            .unwrap();
+                            assert_eq!(id.info().unwrap(), info);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[allow(clippy::too_many_lines)]
+    #[test]
+    fn new_full_rejects_the_values_out_of_range() {
+        let new_full = |instance, authentication_server, account| {
+            Id64::new_full(
+                Universe::Public,
+                AccountType::Individual,
+                instance,
+                authentication_server,
+                account,
+            )
+            .unwrap_err()
+        };
+        let out_of_range = |field, value| crate::error::Error::OutOfRange { field, value };
+        assert_eq!(
+            new_full(MAX_INSTANCE + 1, 0, 1),
+            out_of_range(Field::Instance, u64::from(MAX_INSTANCE) + 1)
+        );
+        assert_eq!(
+            new_full(1, 0, MAX_ACCOUNT + 1),
+            out_of_range(Field::Account, u64::from(MAX_ACCOUNT) + 1)
+        );
+        assert_eq!(
+            new_full(1, 2, 1),
+            out_of_range(Field::AuthenticationServer, 2)
+        );
+        assert_eq!(
+            new_full(1, u8::MAX, MAX_ACCOUNT),
+            out_of_range(Field::AuthenticationServer, 255)
+        );
+    }
+
+    #[test]
+    fn new_full_rejects_known_values_as_unknown() {
+        let known_as_unknown = |field, value| crate::error::Error::KnownAsUnknown { field, value };
+        assert_eq!(
+            Id64::new_full(Universe::Unknown(1), AccountType::Individual, 1, 0, 1).unwrap_err(),
+            known_as_unknown(Field::Universe, 1)
+        );
+        assert_eq!(
+            Id64::new_full(Universe::Public, AccountType::Unknown(7), 0, 0, 1).unwrap_err(),
+            known_as_unknown(Field::AccountType, 7)
+        );
+        assert!(
+            Id64::checked_new_full(Universe::Unknown(5), AccountType::Individual, 1, 0, 1)
+                .is_none()
+        );
+        assert!(
+            Id64::checked_new_full(Universe::Public, AccountType::Unknown(0), 0, 0, 1).is_none()
+        );
+        let id = Id64::new_full(Universe::Unknown(6), AccountType::Unknown(11), 0, 0, 1).unwrap();
+        assert_eq!(id.universe(), Universe::Unknown(6));
+        assert_eq!(id.account_type(), AccountType::Unknown(11));
+    }
 }
@@ -0,0 +1,232 @@
+//! Steam "quick invite" codes and links.
+//!
+//! The steam client allows to share a short link to the profile, like
+//! `https://s.team/p/chw-rfbr/ABCDEFGH`. The `chw-rfbr` part there is the
+//! account id of an individual account written in hexadecimal, but with the
+//! custom alphabet (`bcdfghjkmnpqrtvw`) instead of the usual hexadecimal digits
+//! and split into two halves with a dash. The last part is the invite token,
+//! which is optional for just finding out who the link belongs to.
+//!
+//! Example:
+//!
+//! ```rust
+//! use std::convert::TryFrom;
+//! use std::str::FromStr;
+//! use steamidfx::id::Id64;
+//! use steamidfx::invite::{InviteCode, InviteUrl};
+//!
+//! let id = Id64(76561197983318796);
+//! let code = InviteCode::try_from(id).unwrap();
+//! assert_eq!(code, InviteCode("chw-rfbr".to_owned()));
+//! assert_eq!(Id64::try_from(code).unwrap(), id);
+//!
+//! let url = InviteUrl::from_str("https://s.team/p/chw-rfbr/ABCDEFGH").unwrap();
+//! assert_eq!(url.id64().unwrap(), id);
+//! assert_eq!(url.token.as_deref(), Some("ABCDEFGH"));
+//! assert_eq!(url.to_string(), "https://s.team/p/chw-rfbr/ABCDEFGH");
+//! ```
+use crate::account_id::{individual_account_id, AccountId};
+use crate::community::strip_scheme;
+use crate::id::Id64;
+use alloc::borrow::ToOwned;
+use alloc::format;
+use alloc::string::String;
+use core::convert::TryFrom;
+
+/// The alphabet used instead of the hexadecimal digits `0123456789abcdef`.
+const INVITE_CODE_ALPHABET: &[u8; 16] = b"bcdfghjkmnpqrtvw";
+/// The host and the path the invite links are served from.
+const INVITE_URL_PREFIX: &str = "s.team/p/";
+
+/// Steam invite code.
+/// Example: `chw-rfbr`.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, Ord, PartialOrd, Hash, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct InviteCode(pub String);
+
+impl InviteCode {
+    /// Decodes the account id (the account number and the authentication server bit) from the code.
+    /// The dashes are ignored wherever they are.
+    ///
+    /// # Errors
+    /// Returns an error if the code is empty, contains characters outside of the alphabet,
+    /// doesn't fit into an account id or encodes the account id `0`, which no account has.
+    pub fn account_id(&self) -> crate::error::Result<AccountId> {
+        let invalid = || crate::error::Error::invalid_format("invite code", self.0.clone());
+        let mut digits = self.0.chars().filter(|c| *c != '-').peekable();
+        if digits.peek().is_none() {
+            return Err(invalid());
+        }
+        let account_id = digits.try_fold(0u32, |account_id, c| {
+            let digit = INVITE_CODE_ALPHABET
+                .iter()
+                .position(|d| char::from(*d) == c)
+                .ok_or_else(invalid)?;
+            account_id
+                .checked_mul(16)
+                .and_then(|a| a.checked_add(u32::try_from(digit).ok()?))
+                .ok_or_else(invalid)
+        })?;
+        if account_id == 0 {
+            return Err(invalid());
+        }
+        Ok(AccountId(account_id))
+    }
+}
+
+impl core::fmt::Display for InviteCode {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        fmt.write_str(&self.0)
+    }
+}
+
+/// Encodes the account id of the steam id, see [`AccountId`] for which ids convert.
+impl TryFrom<Id64> for InviteCode {
+    type Error = crate::error::Error;
+
+    fn try_from(id: Id64) -> crate::error::Result<InviteCode> {
+        let account_id = individual_account_id(&id.info()?, "an invite code")?;
+        id.validate()?;
+        let mut code: String = format!("{:x}", account_id.0)
+            .chars()
+            .filter_map(|c| c.to_digit(16))
+            .map(|digit| char::from(INVITE_CODE_ALPHABET[digit as usize]))
+            .collect();
+        let split = code.len() / 2;
+        if split > 0 {
+            code.insert(split, '-');
+        }
+        Ok(InviteCode(code))
+    }
+}
+
+impl TryFrom<InviteCode> for Id64 {
+    type Error = crate::error::Error;
+
+    fn try_from(code: InviteCode) -> crate::error::Result<Id64> {
+        Ok(Id64::from(code.account_id()?))
+    }
+}
+
+/// Steam invite link.
+/// Example: `https://s.team/p/chw-rfbr/ABCDEFGH`.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, Ord, PartialOrd, Hash, PartialEq, Eq)]
+pub struct InviteUrl {
+    /// The invite code of the account.
+    pub code: InviteCode,
+    /// The invite token, if the link contains it.
+    pub token: Option<String>,
+}
+
+impl InviteUrl {
+    /// Creates an invite link for the individual account, see [`AccountId`] for which ids convert.
+    ///
+    /// # Errors
+    /// Returns an error if the id can't be written as an invite code.
+    pub fn new(id: Id64, token: Option<String>) -> crate::error::Result<InviteUrl> {
+        Ok(InviteUrl {
+            code: InviteCode::try_from(id)?,
+            token,
+        })
+    }
+
+    /// Returns the steam id 64 of the account the link invites to.
+    ///
+    /// # Errors
+    /// Returns an error if the invite code is incorrect.
+    pub fn id64(&self) -> crate::error::Result<Id64> {
+        Id64::try_from(self.code.clone())
+    }
+}
+
+impl core::str::FromStr for InviteUrl {
+    type Err = crate::error::Error;
+
+    fn from_str(value: &str) -> crate::error::Result<Self> {
+        let invalid = || crate::error::Error::invalid_format("invite link", value);
+        let path = strip_scheme(value)
+            .strip_prefix(INVITE_URL_PREFIX)
+            .ok_or_else(invalid)?;
+        let mut parts = path.trim_end_matches('/').splitn(2, '/');
+        let code = InviteCode(parts.next().unwrap_or_default().to_owned());
+        code.account_id().map_err(|_| invalid())?;
+        let token = match parts.next() {
+            Some(token)
+                if !token.is_empty() && token.chars().all(|c| c.is_ascii_alphanumeric()) =>
+            {
+                Some(token.to_owned())
+            }
+            Some(_) => return Err(invalid()),
+            None => None,
+        };
+        Ok(InviteUrl { code, token })
+    }
+}
+
+impl core::fmt::Display for InviteUrl {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "https://{}{}", INVITE_URL_PREFIX, self.code)?;
+        if let Some(token) = &self.token {
+            write!(fmt, "/{token}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::str::FromStr;
+
+    #[allow(clippy::unreadable_literal)]
+    #[test]
+    fn invite_code_round_trip() {
+        for id in &[76561197960287930u64, 76561197983318796, 76561198000000001] {
+            let code = InviteCode::try_from(Id64(*id)).unwrap();
+            assert_eq!(Id64::try_from(code).unwrap(), Id64(*id));
+        }
+        assert_eq!(
+            InviteCode::try_from(Id64(76561197960287930)).unwrap(),
+            InviteCode("hj-qp".to_owned())
+        );
+        assert!(InviteCode::try_from(Id64(103582791429521412)).is_err());
+        assert!(InviteCode::try_from(Id64(148618792021246732)).is_err());
+        assert!(InviteCode::try_from(Id64(76561210868220684)).is_err());
+        assert!(InviteUrl::new(Id64(148618792021246732), None).is_err());
+        let one_digit = InviteCode::try_from(Id64(76561197960265729)).unwrap();
+        assert_eq!(one_digit, InviteCode("c".to_owned()));
+        assert_eq!(Id64::try_from(one_digit).unwrap(), Id64(76561197960265729));
+    }
+
+    #[allow(clippy::unreadable_literal)]
+    #[test]
+    fn invite_code_invalid() {
+        assert!(InviteCode(String::new()).account_id().is_err());
+        assert!(InviteCode("b".to_owned()).account_id().is_err());
+        assert!(InviteCode::try_from(Id64(76561197960265728)).is_err());
+        assert!(InviteCode("-".to_owned()).account_id().is_err());
+        assert!(InviteCode("chw-rfba".to_owned()).account_id().is_err());
+        assert!(InviteCode("wwwww-wwwww".to_owned()).account_id().is_err());
+    }
+
+    #[allow(clippy::unreadable_literal)]
+    #[test]
+    fn invite_url_parse() {
+        let url = InviteUrl::from_str("s.team/p/chw-rfbr").unwrap();
+        assert_eq!(url.token, None);
+        assert_eq!(url.id64().unwrap(), Id64(76561197983318796));
+        assert_eq!(url.to_string(), "https://s.team/p/chw-rfbr");
+        assert!(InviteUrl::from_str("http://s.team/p/chw-rfbr/").is_ok());
+        assert!(InviteUrl::from_str("https://s.team/p/chw-rfbr/AB/CD").is_err());
+        assert!(InviteUrl::from_str("https://s.team/p/chw-rfbr/ÄÖÜ").is_err());
+        assert!(InviteUrl::from_str("https://s.team/p/b").is_err());
+        assert!(InviteUrl::from_str("https://s.team/p/").is_err());
+        assert!(InviteUrl::from_str("https://https://s.team/p/chw-rfbr").is_err());
+        assert!(InviteUrl::from_str("https://example.com/p/chw-rfbr").is_err());
+    }
+}
@@ -5,16 +5,48 @@
 //! but this crate deviates a bit from it as empirically it was
 //! noticed that the valve themselves don't follow this spec fully.
 //! To be in-line with valve's behaviour, this crates deviated from the spec.
+//!
+//! The crate is `no_std` (but needs `alloc`) when the default `std` feature is
+//! disabled. Then the errors don't implement `std::error::Error`, and the scanner
+//! (which uses the regular expressions) isn't available.
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![deny(warnings)]
 #![deny(missing_docs)]
 #![deny(clippy::all)]
 #![deny(clippy::pedantic)]
 
+extern crate alloc;
+
+/// The macros of the crate.
+#[macro_use]
+mod macros;
+
+/// The 32-bit account ids.
+pub mod account_id;
 /// An iterator over bits.
 pub mod bit_iterator;
+/// Building steam ids 64 field by field.
+pub mod builder;
+/// The steam community links.
+pub mod community;
+/// Rendering steam ids in any format.
+pub mod display;
 /// The errors used in this crate.
 pub mod error;
+/// The CS:GO friend codes.
+pub mod friend_code;
 /// The steam ID implementation.
 pub mod id;
+/// The steam invite codes and links.
+pub mod invite;
+/// Parsing steam ids in different modes.
+pub mod parse;
+/// Finding steam ids in arbitrary text.
+#[cfg(feature = "std")]
+pub mod scan;
 /// The services the crate can work with regarding the steam id information.
 pub mod services;
+/// The steam trade offer links.
+pub mod trade;
+/// Validating steam ids: the diagnostics of the rejected ids and valve's validity rules.
+pub mod validate;
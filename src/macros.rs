@@ -0,0 +1,45 @@
+//! The macros of the crate.
+
+/// Unwraps the option in the `const fn`s, where the `?` operator can't be used.
+macro_rules! const_try {
+    ($option:expr) => {
+        match $option {
+            Some(value) => value,
+            None => return None,
+        }
+    };
+}
+
+/// Parses the steam id in any format (the steam id 64, `STEAM_X:Y:Z` or the steam id 3) at
+/// compile time into an [`Id64`](crate::id::Id64) constant. An incorrect steam id fails the
+/// compilation.
+///
+/// Example:
+///
+/// ```rust
+/// use steamidfx::id::Id64;
+/// use steamidfx::steamid;
+///
+/// const ADMIN: Id64 = steamid!("STEAM_0:0:11526534");
+/// assert_eq!(ADMIN, Id64(76561197983318796));
+/// assert_eq!(steamid!("[U:1:23053068]"), ADMIN);
+/// assert_eq!(steamid!("76561197983318796"), ADMIN);
+/// ```
+///
+/// ```compile_fail
+/// let id = steamidfx::steamid!("STEAM_0:2:11526534");
+/// ```
+///
+/// ```compile_fail
+/// let id = steamidfx::steamid!("18446744073709551615");
+/// ```
+#[macro_export]
+macro_rules! steamid {
+    ($id:literal) => {{
+        const ID: $crate::id::Id64 = match $crate::id::Id64::checked_parse($id.as_bytes()) {
+            Some(id) => id,
+            None => panic!(concat!("Invalid steam id: ", $id)),
+        };
+        ID
+    }};
+}
@@ -0,0 +1,391 @@
+//! Parsing steam ids from the strings of different origins.
+//!
+//! The `FromStr` implementation of [`Id`](crate::id::Id) accepts the well-formed
+//! ids only, but doesn't check the ranges of their fields. For the untrusted input,
+//! the strict mode should be used, which accepts only the ASCII ids and checks that
+//! all the fields fit the steam id. For the input pasted by the users, the lenient
+//! mode should be used, which also accepts the ids surrounded by whitespace, quotes
+//! or brackets, the lowercase `steam_` prefix and the community and invite links.
+//!
+//! When only the steam id 64 is needed,
+//! [`Id64::parse_bytes`](crate::id::Id64::parse_bytes) (or the `FromStr`
+//! implementation of [`Id64`](crate::id::Id64)) should be used: it parses all the
+//! formats in a single pass, without allocating a `String` for the id, and checks
+//! that all the fields fit the steam id.
+//!
+//! Example:
+//!
+//! ```rust
+//! use steamidfx::id::{Id, Id32, Id64};
+//! use steamidfx::parse::ParseMode;
+//!
+//! assert!(Id::parse("STEAM_0:2:11526534", ParseMode::Strict).is_err());
+//! assert_eq!(
+//!     Id::parse(" steam_0:1:11526534\n", ParseMode::Lenient).unwrap(),
+//!     Id::Id32(Id32("STEAM_0:1:11526534".to_owned()))
+//! );
+//!
+//! let id: Id64 = "[U:1:23053068]".parse().unwrap();
+//! assert_eq!(id, Id64(76561197983318796));
+//! assert_eq!(Id64::parse_bytes(b"STEAM_0:0:11526534").unwrap(), id);
+//! ```
+use crate::community::CommunityUrl;
+use crate::id::{AccountType, Id, Id32Fields, Id3Fields, Id64, Universe, MAX_INSTANCE};
+use crate::invite::InviteUrl;
+use alloc::borrow::ToOwned;
+use alloc::format;
+use alloc::string::String;
+use core::str::FromStr;
+
+/// The way the steam ids are parsed from the strings.
+#[derive(Debug, Copy, Clone, Default, Ord, PartialOrd, Hash, PartialEq, Eq)]
+pub enum ParseMode {
+    /// The same as the `FromStr` implementation of [`Id`]. This is the default.
+    #[default]
+    Standard,
+    /// Accepts only the ASCII ids with all the fields within their ranges: the universe and the
+    /// account type are known and aren't the invalid ones, the authentication server is either
+    /// `0` or `1`, the account number fits 31 bits and the instance fits 20 bits.
+    /// [`Id::validate`] reports why an id is rejected in this mode.
+    Strict,
+    /// Accepts the ids surrounded by whitespace, quotes or brackets, with the `steam_` prefix in
+    /// any case, and the community profile and invite links.
+    Lenient,
+}
+
+impl Id {
+    /// Parses the steam id in any format using the parse mode passed.
+    ///
+    /// # Errors
+    /// Returns an error if the string doesn't contain a steam id acceptable in the mode passed.
+    pub fn parse(value: &str, mode: ParseMode) -> crate::error::Result<Id> {
+        match mode {
+            ParseMode::Standard => Id::from_str(value),
+            ParseMode::Strict => parse_strict(value),
+            ParseMode::Lenient => parse_lenient(value),
+        }
+    }
+}
+
+/// Parses the steam id accepting only the correct ASCII ids.
+fn parse_strict(value: &str) -> crate::error::Result<Id> {
+    Ok(Id::validate(value)?)
+}
+
+/// Parses the steam id normalizing everything the users usually paste along with it.
+fn parse_lenient(value: &str) -> crate::error::Result<Id> {
+    let value = value
+        .trim()
+        .trim_matches(|c| matches!(c, '"' | '\'' | '`' | '<' | '>' | '(' | ')'))
+        .trim();
+    if let Ok(url) = CommunityUrl::from_str(value) {
+        return url
+            .id()
+            .cloned()
+            .ok_or_else(|| crate::error::Error::Unresolved(value.to_owned()));
+    }
+    if let Ok(url) = InviteUrl::from_str(value) {
+        return Ok(Id::Id64(url.id64()?));
+    }
+    let unbracketed = value
+        .strip_prefix('[')
+        .and_then(|v| v.strip_suffix(']'))
+        .map(str::trim);
+    Id::from_str(&normalize_prefix(value))
+        .or_else(|e| unbracketed.map_or(Err(e), |v| Id::from_str(&normalize_prefix(v))))
+}
+
+/// Makes the `STEAM_` prefix of the steam id 32 uppercase.
+fn normalize_prefix(value: &str) -> String {
+    match value.get(..6) {
+        Some(prefix) if prefix.eq_ignore_ascii_case("STEAM_") => format!("STEAM_{}", &value[6..]),
+        _ => value.to_owned(),
+    }
+}
+
+/// A cursor over the bytes of the steam id being parsed. All the methods are `const`, so they
+/// return the cursor moved past what they have read instead of changing it.
+#[derive(Copy, Clone)]
+pub(crate) struct Cursor<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Cursor<'a> {
+    pub(crate) const fn new(bytes: &'a [u8]) -> Self {
+        Cursor { bytes, position: 0 }
+    }
+
+    /// Returns the next byte.
+    pub(crate) const fn next(self) -> Option<(u8, Cursor<'a>)> {
+        if self.position == self.bytes.len() {
+            return None;
+        }
+        Some((self.bytes[self.position], self.advance(1)))
+    }
+
+    /// Moves past the byte passed if it is the next one.
+    pub(crate) const fn eat(self, byte: u8) -> Option<Cursor<'a>> {
+        match self.next() {
+            Some((next, cursor)) if next == byte => Some(cursor),
+            _ => None,
+        }
+    }
+
+    /// Moves past the bytes passed if they are the next ones.
+    pub(crate) const fn eat_prefix(self, prefix: &[u8]) -> Option<Cursor<'a>> {
+        let mut cursor = self;
+        let mut i = 0;
+        while i < prefix.len() {
+            cursor = const_try!(cursor.eat(prefix[i]));
+            i += 1;
+        }
+        Some(cursor)
+    }
+
+    /// Reads a single decimal digit.
+    pub(crate) const fn digit(self) -> Option<(u8, Cursor<'a>)> {
+        match self.next() {
+            Some((digit @ b'0'..=b'9', cursor)) => Some((digit - b'0', cursor)),
+            _ => None,
+        }
+    }
+
+    /// Reads a decimal number of at least one digit which fits `u64`.
+    pub(crate) const fn number(self) -> Option<(u64, Cursor<'a>)> {
+        let (digit, mut cursor) = const_try!(self.digit());
+        let mut number = digit as u64;
+        while let Some((digit, next)) = cursor.digit() {
+            number = const_try!(const_try!(number.checked_mul(10)).checked_add(digit as u64));
+            cursor = next;
+        }
+        Some((number, cursor))
+    }
+
+    /// Reads a decimal number following the separator passed.
+    pub(crate) const fn number_after(self, separator: u8) -> Option<(u64, Cursor<'a>)> {
+        const_try!(self.eat(separator)).number()
+    }
+
+    /// Returns `true` if all the bytes have been read.
+    pub(crate) const fn is_end(self) -> bool {
+        self.position == self.bytes.len()
+    }
+
+    const fn advance(self, count: usize) -> Cursor<'a> {
+        Cursor {
+            bytes: self.bytes,
+            position: self.position + count,
+        }
+    }
+}
+
+impl Id64 {
+    /// Parses the steam id in any format (the steam id 64, `STEAM_X:Y:Z` or the steam id 3, with
+    /// or without the square brackets) straight into the steam id 64. Unlike converting the
+    /// [`Id`] parsed, this reads the input in a single pass, doesn't allocate a `String` for the
+    /// id, and checks the fields fit the steam id.
+    ///
+    /// # Errors
+    /// Returns an error if the bytes don't contain a steam id in one of the formats.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use steamidfx::id::Id64;
+    ///
+    /// assert_eq!(Id64::parse_bytes(b"[g:1:4]").unwrap(), Id64(103582791429521412));
+    /// assert!(Id64::parse_bytes(b"STEAM_0:2:11526534").is_err());
+    /// ```
+    pub fn parse_bytes(value: &[u8]) -> crate::error::Result<Id64> {
+        parse_id64(value).ok_or_else(|| {
+            crate::error::Error::invalid_format("steam id", String::from_utf8_lossy(value))
+        })
+    }
+}
+
+impl FromStr for Id64 {
+    type Err = crate::error::Error;
+
+    fn from_str(value: &str) -> crate::error::Result<Self> {
+        Id64::parse_bytes(value.as_bytes())
+    }
+}
+
+/// Parses the steam id in any format, choosing the format by the first byte.
+pub(crate) const fn parse_id64(value: &[u8]) -> Option<Id64> {
+    match value {
+        [b'0'..=b'9', ..] => match Cursor::new(value).number() {
+            Some((number, cursor)) if cursor.is_end() => known_id64(Id64(number)),
+            _ => None,
+        },
+        [b'S', ..] => parse_id32(value),
+        _ => parse_id3(value),
+    }
+}
+
+/// Accepts the steam id 64 only if its universe and account type are known, the same way the
+/// conversion of the number into [`Id`] does.
+const fn known_id64(id: Id64) -> Option<Id64> {
+    if id.universe().is_known() && id.account_type().is_known() {
+        Some(id)
+    } else {
+        None
+    }
+}
+
+/// Parses the steam id 32 (`STEAM_X:Y:Z`) of an individual account.
+const fn parse_id32(value: &[u8]) -> Option<Id64> {
+    let fields = const_try!(Id32Fields::parse_bytes(value));
+    Id64::checked_new_simple(
+        const_try!(fields.checked_universe()),
+        fields.authentication_server,
+        fields.account,
+    )
+}
+
+/// Parses the steam id 3 (`L:U:A` or `L:U:A:I`), with or without the square brackets.
+const fn parse_id3(value: &[u8]) -> Option<Id64> {
+    let fields = const_try!(Id3Fields::parse_bytes(value));
+    let instance = fields.instance();
+    if matches!(fields.account_type, AccountType::Invalid) || instance > MAX_INSTANCE {
+        return None;
+    }
+    Some(Id64::from_parts(
+        const_try!(Universe::from_bits(fields.universe as u64)),
+        fields.account_type,
+        instance,
+        fields.account_id,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::id::{Id3, Id32, Id64};
+
+    #[allow(clippy::too_many_lines)]
+    #[test]
+    fn parse_strict() {
+        let valid = [
+            "76561197983318796",
+            "STEAM_0:1:11526534",
+            "STEAM_1:0:2147483647",
+            "[U:1:23053068]",
+            "[A:1:123:1048575]",
+        ];
+        for value in &valid {
+            assert_eq!(
+                Id::parse(value, ParseMode::Strict).unwrap(),
+                Id::from_str(value).unwrap()
+            );
+        }
+        let invalid = [
+            "+76561197983318796",
+            "STEAM_0:2:11526534",
+            "STEAM_0:0:2147483648",
+            "STEAM_7:0:11526534",
+            "STEAM_0:0:١١٥٢٦٥٣٤",
+            "[U:7:23053068]",
+            "[A:1:123:1048576]",
+            "[Ü:1:23053068]",
+            "0",
+            "[I:1:5]",
+            "[U:0:23053068]",
+        ];
+        for value in &invalid {
+            assert!(Id::parse(value, ParseMode::Strict).is_err(), "{}", value);
+        }
+    }
+
+    #[allow(clippy::unreadable_literal)]
+    #[allow(clippy::too_many_lines)]
+    #[test]
+    fn parse_lenient() {
+        let id32 = Id::Id32(Id32("STEAM_0:1:11526534".to_owned()));
+        let id64 = Id::Id64(Id64(76561197983318796));
+        let cases = [
+            ("  steam_0:1:11526534\r\n", &id32),
+            ("\"Steam_0:1:11526534\"", &id32),
+            ("[STEAM_0:1:11526534]", &id32),
+            ("<76561197983318796>", &id64),
+            (
+                "https://steamcommunity.com/profiles/76561197983318796/",
+                &id64,
+            ),
+            ("https://s.team/p/chw-rfbr/ABCDEFGH", &id64),
+        ];
+        for (value, expected) in &cases {
+            assert_eq!(&&Id::parse(value, ParseMode::Lenient).unwrap(), expected);
+        }
+        assert_eq!(
+            Id::parse(" [U:1:23053068] ", ParseMode::Lenient).unwrap(),
+            Id::Id3(Id3("[U:1:23053068]".to_owned()))
+        );
+        assert!(Id::parse("https://steamcommunity.com/id/gaben", ParseMode::Lenient).is_err());
+        assert!(Id::parse("steam 0:1:11526534", ParseMode::Lenient).is_err());
+    }
+
+    #[allow(clippy::unreadable_literal)]
+    #[allow(clippy::too_many_lines)]
+    #[test]
+    fn parse_id64_same_as_id() {
+        let values = [
+            "76561197983318796",
+            "103582791429521412",
+            "STEAM_0:1:11526534",
+            "STEAM_1:0:11526534",
+            "STEAM_4:1:2147483647",
+            "[U:1:23053068]",
+            "U:1:23053069",
+            "[U:1:23053068:7]",
+            "[g:1:4]",
+            "[A:1:123:4567]",
+            "[G:1:3771155]",
+            "[L:1:4]",
+            "[c:1:4]",
+            "[T:1:4:262144]",
+            "[a:2:5]",
+            "[M:1:4294967295:1048575]",
+        ];
+        for value in &values {
+            assert_eq!(
+                Id64::from_str(value).unwrap(),
+                Id::from_str(value).unwrap().id64().unwrap(),
+                "{value}"
+            );
+        }
+    }
+
+    #[allow(clippy::too_many_lines)]
+    #[test]
+    fn parse_id64_invalid() {
+        let invalid = [
+            "",
+            "[]",
+            "+76561197983318796",
+            "765611979833187960000",
+            "18446744073709551615",
+            "121597189939003397",
+            "7656119798331879a",
+            "STEAM_0:2:11526534",
+            "STEAM_0:0:2147483648",
+            "STEAM_9:0:11526534",
+            "STEAM_0:0:",
+            "STEAM_00:0:1",
+            "steam_0:0:1",
+            "[U:1:23053068",
+            "U:1:23053068]",
+            "[I:1:5]",
+            "[Q:1:5]",
+            "[U:7:5]",
+            "[U:1:4294967296]",
+            "[A:1:123:1048576]",
+            "[U:1:5:]",
+            "[Ü:1:5]",
+        ];
+        for value in &invalid {
+            assert!(Id64::from_str(value).is_err(), "{}", value);
+        }
+    }
+}
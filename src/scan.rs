@@ -0,0 +1,135 @@
+//! Finding steam ids in arbitrary text: chat logs, support tickets, server logs.
+//!
+//! The scanner finds the steam ids 64, the steam ids 32 (`STEAM_X:Y:Z`), the
+//! steam ids 3 (both `U:1:Z` and `[U:1:Z]`) and the community profile links,
+//! and yields them along with their byte ranges within the text.
+//!
+//! Example:
+//!
+//! ```rust
+//! use steamidfx::id::{Id, Id3, Id32, Id64};
+//!
+//! let text = "L 10/16/2026 - \"player<2><[U:1:23053068]><Red>\" killed STEAM_0:1:11526534";
+//! let found: Vec<_> = steamidfx::scan::scan(text).collect();
+//! assert_eq!(found.len(), 2);
+//! assert_eq!(found[0].id, Id::Id3(Id3("[U:1:23053068]".to_owned())));
+//! assert_eq!(&text[found[0].range.clone()], "[U:1:23053068]");
+//! assert_eq!(found[1].id, Id::Id32(Id32("STEAM_0:1:11526534".to_owned())));
+//! ```
+#![allow(clippy::non_std_lazy_statics)]
+use crate::community::CommunityUrl;
+use crate::id::{Id, Id3, Id32, Id64, Universe};
+use regex::Regex;
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+/// The grammar of a steam id 32.
+const ID32_PATTERN: &str = r"STEAM_([0-9]):([0-9]):([0-9]+)";
+/// The grammar of a steam id 3 without the square brackets.
+const ID3_PATTERN: &str = r"([A-Za-z]):([0-9]):([0-9]+)(?::([0-9]+))?";
+
+lazy_static::lazy_static! {
+    static ref SCAN_REGEXP: Regex = {
+        Regex::new(&format!(
+            r"(?P<url>(?:https?://)?(?:www\.)?steamcommunity\.com/(?:profiles|gid)/[^\s/?#]+|steam://url/(?:Group)?SteamIDPage/[0-9]+)|(?P<id32>\b{ID32_PATTERN}\b)|(?P<id3>\[{ID3_PATTERN}\]|\b{ID3_PATTERN}\b)|(?P<id64>\b[0-9]{{17,20}}\b)"
+        ))
+        .unwrap()
+    };
+}
+
+/// A steam id found in the text.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Found {
+    /// The byte range of the steam id (or of the link containing it) within the text.
+    pub range: std::ops::Range<usize>,
+    /// The steam id found.
+    pub id: Id,
+}
+
+/// An iterator over the steam ids found in the text. Created by [`scan`].
+#[allow(clippy::module_name_repetitions)]
+pub struct Scanner<'a> {
+    matches: regex::CaptureMatches<'static, 'a>,
+}
+
+/// Returns an iterator over all the steam ids found in the text.
+#[must_use]
+pub fn scan(text: &str) -> Scanner<'_> {
+    Scanner {
+        matches: SCAN_REGEXP.captures_iter(text),
+    }
+}
+
+/// Parses the steam id 64 found, accepting only the numbers looking like a real steam id.
+fn parse_id64(value: &str) -> Option<Id> {
+    let id = Id64(value.parse().ok()?);
+    if id.universe() == Universe::Invalid {
+        return None;
+    }
+    Id::try_from(id.0).ok()
+}
+
+/// Parses the text the regular expression has matched into a steam id.
+fn parse_found(captures: &regex::Captures<'_>) -> Option<Id> {
+    if let Some(url) = captures.name("url") {
+        let url = CommunityUrl::from_str(url.as_str()).ok()?;
+        return url.id().cloned();
+    }
+    if let Some(id32) = captures.name("id32") {
+        let id32 = Id32(id32.as_str().to_owned());
+        Id64::try_from(id32.clone()).ok()?;
+        return Some(Id::Id32(id32));
+    }
+    if let Some(id3) = captures.name("id3") {
+        let id3 = Id3(id3.as_str().to_owned());
+        id3.info().ok()?;
+        return Some(Id::Id3(id3));
+    }
+    parse_id64(captures.name("id64")?.as_str())
+}
+
+impl Iterator for Scanner<'_> {
+    type Item = Found;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.matches.find_map(|captures| {
+            let range = captures.get(0)?.range();
+            parse_found(&captures).map(|id| Found { range, id })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::unreadable_literal)]
+    #[test]
+    fn scan_all_formats() {
+        let text = "76561197983318796, U:1:23053068;STEAM_1:0:11526534\n\
+                    https://steamcommunity.com/profiles/76561197983318796/ and [A:1:123:4567]";
+        let found: Vec<_> = scan(text).collect();
+        let ranges: Vec<_> = found.iter().map(|f| &text[f.range.clone()]).collect();
+        assert_eq!(
+            ranges,
+            vec![
+                "76561197983318796",
+                "U:1:23053068",
+                "STEAM_1:0:11526534",
+                "https://steamcommunity.com/profiles/76561197983318796",
+                "[A:1:123:4567]",
+            ]
+        );
+        assert_eq!(found[0].id, Id::Id64(Id64(76561197983318796)));
+        assert_eq!(found[3].id, Id::Id64(Id64(76561197983318796)));
+    }
+
+    #[test]
+    fn scan_ignores_lookalikes() {
+        let text = "12345678901234567 x:1:2 at 12:30:15, XSTEAM_0:0:1 [Q:1:5] 18446744073709551616";
+        assert_eq!(scan(text).count(), 0);
+        assert_eq!(scan("").count(), 0);
+        let text = "STEAM_0:2:5 STEAM_9:0:1 STEAM_0:0:99999999999 STEAM_0:0:١٢ U:١:5 ü:1:5";
+        assert_eq!(scan(text).count(), 0);
+    }
+}
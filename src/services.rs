@@ -1,5 +1,7 @@
 //! The requests feature implementation which allows making requests to different services for working with
 //! steam id.
+use alloc::format;
+use alloc::string::String;
 
 /// Steam profile from <https://steamid.co>.
 #[derive(Debug, Clone)]
@@ -0,0 +1,171 @@
+//! Steam trade offer links.
+//!
+//! A trade offer link, like
+//! `https://steamcommunity.com/tradeoffer/new/?partner=23053068&token=AbCd-_12`,
+//! carries the account id of an individual account (the account number and the
+//! authentication server bit), not the steam id 64, and the trade token.
+//!
+//! Example:
+//!
+//! ```rust
+//! use std::str::FromStr;
+//! use steamidfx::id::Id64;
+//! use steamidfx::trade::TradeUrl;
+//!
+//! let url = TradeUrl::from_str(
+//!     "https://steamcommunity.com/tradeoffer/new/?partner=23053068&token=AbCd-_12",
+//! )
+//! .unwrap();
+//! assert_eq!(url.id, Id64(76561197983318796));
+//! assert_eq!(url.token, "AbCd-_12");
+//! assert_eq!(
+//!     url.to_string(),
+//!     "https://steamcommunity.com/tradeoffer/new/?partner=23053068&token=AbCd-_12"
+//! );
+//! ```
+use crate::account_id::{individual_account_id, AccountId};
+use crate::community::{strip_host, COMMUNITY_URL};
+use crate::id::Id64;
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+
+/// The path of the trade offer links.
+const TRADE_OFFER_PATH: &str = "tradeoffer/new/";
+/// What the trade offer links are called in the errors.
+const TRADE_OFFER_LINK: &str = "a trade offer link";
+/// The length of a trade token.
+const TRADE_TOKEN_LENGTH: usize = 8;
+
+/// Steam trade offer link.
+/// Example: `https://steamcommunity.com/tradeoffer/new/?partner=xxxxxxxx&token=xxxxxxxx`.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, Ord, PartialOrd, Hash, PartialEq, Eq)]
+pub struct TradeUrl {
+    /// The steam id of the trade partner.
+    pub id: Id64,
+    /// The trade token of the trade partner.
+    pub token: String,
+}
+
+/// Checks the trade token consists of the eight characters steam uses for it.
+fn is_valid_token(token: &str) -> bool {
+    token.len() == TRADE_TOKEN_LENGTH
+        && token
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+impl TradeUrl {
+    /// Creates a trade offer link for the individual account. The `partner` parameter carries
+    /// just the account id, see [`AccountId`] for which ids convert.
+    ///
+    /// # Errors
+    /// Returns an error if the id can't be written as the `partner` parameter, its account id is
+    /// `0` or the token is malformed.
+    pub fn new(id: Id64, token: String) -> crate::error::Result<TradeUrl> {
+        individual_account_id(&id.info()?, TRADE_OFFER_LINK)?;
+        id.validate()?;
+        if !is_valid_token(&token) {
+            return Err(crate::error::Error::invalid_format("trade token", token));
+        }
+        Ok(TradeUrl { id, token })
+    }
+
+    /// Returns the account id written in the `partner` parameter of the link.
+    ///
+    /// # Errors
+    /// Returns an error if the id is incorrect or can't be written as the account id.
+    pub fn partner(&self) -> crate::error::Result<AccountId> {
+        individual_account_id(&self.id.info()?, TRADE_OFFER_LINK)
+    }
+}
+
+impl core::str::FromStr for TradeUrl {
+    type Err = crate::error::Error;
+
+    fn from_str(value: &str) -> crate::error::Result<Self> {
+        let invalid = || crate::error::Error::invalid_format("trade offer link", value);
+        let query = strip_host(value)
+            .and_then(|path| path.strip_prefix(TRADE_OFFER_PATH))
+            .and_then(|path| path.strip_prefix('?'))
+            .ok_or_else(invalid)?;
+        let (mut partner, mut token) = (None, None);
+        for (name, value) in query.split('&').filter_map(|p| p.split_once('=')) {
+            match name {
+                "partner" => partner = value.parse::<u32>().ok().map(AccountId),
+                "token" => token = Some(value.to_owned()),
+                _ => {}
+            }
+        }
+        let id = Id64::from(partner.ok_or_else(invalid)?);
+        TradeUrl::new(id, token.ok_or_else(invalid)?)
+    }
+}
+
+/// Writes the account id of the `id` as the `partner` parameter, even if the `id` can't be
+/// written as one: the fields are public, so use [`TradeUrl::partner`] to check it.
+impl core::fmt::Display for TradeUrl {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            fmt,
+            "{}/{}?partner={}&token={}",
+            COMMUNITY_URL,
+            TRADE_OFFER_PATH,
+            self.id.account_id(),
+            self.token
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::str::FromStr;
+
+    #[allow(clippy::unreadable_literal)]
+    #[test]
+    fn trade_url_parse() {
+        let url = TradeUrl::from_str(
+            "steamcommunity.com/tradeoffer/new/?token=AbCd-_12&partner=23053069&foo=bar",
+        )
+        .unwrap();
+        assert_eq!(url.id, Id64(76561197983318797));
+        assert_eq!(url.partner().unwrap(), AccountId(23053069));
+        assert_eq!(
+            TradeUrl::from_str(&url.to_string()).unwrap(),
+            TradeUrl::new(Id64(76561197983318797), "AbCd-_12".to_owned()).unwrap()
+        );
+    }
+
+    #[allow(clippy::unreadable_literal)]
+    #[test]
+    fn trade_url_invalid() {
+        let base = "https://steamcommunity.com/tradeoffer/new/";
+        assert!(TradeUrl::from_str(&format!("{base}?partner=23053068")).is_err());
+        assert!(TradeUrl::from_str(&format!("{base}?token=AbCd-_12")).is_err());
+        assert!(TradeUrl::from_str(&format!("{base}?partner=x&token=AbCd-_12")).is_err());
+        assert!(TradeUrl::from_str(&format!("{base}?partner=1&token=AbCd")).is_err());
+        assert!(TradeUrl::from_str(&format!("{base}?partner=1&token=AbCd+_12")).is_err());
+        assert!(TradeUrl::from_str("https://example.com/tradeoffer/new/?partner=1").is_err());
+        assert!(TradeUrl::new(Id64(103582791429521412), "AbCd-_12".to_owned()).is_err());
+        assert!(TradeUrl::new(Id64(148618792021246732), "AbCd-_12".to_owned()).is_err());
+        let console = Id64(76561197983318796).with_instance(1025).unwrap();
+        assert!(TradeUrl::new(console, "AbCd-_12".to_owned()).is_err());
+        assert!(TradeUrl::from_str(&format!("{base}?partner=0&token=AbCd-_12")).is_err());
+        assert!(TradeUrl::from_str(&format!("{base}?partner=1&token=AbCd-_12")).is_ok());
+    }
+
+    #[allow(clippy::unreadable_literal)]
+    #[test]
+    fn trade_url_display_unchecked() {
+        let url = TradeUrl {
+            id: Id64(103582791429521412),
+            token: "AbCd-_12".to_owned(),
+        };
+        assert!(url.partner().is_err());
+        assert_eq!(
+            url.to_string(),
+            "https://steamcommunity.com/tradeoffer/new/?partner=4&token=AbCd-_12"
+        );
+    }
+}
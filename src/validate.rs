@@ -0,0 +1,724 @@
+//! Validating steam ids: the diagnostics of the rejected ids and valve's validity rules.
+//!
+//! [`Id::validate`](crate::id::Id::validate) accepts the same ids the strict parse
+//! mode does, but instead of a generic error it reports which field of the id is
+//! wrong, why, and where it is in the input (as a range of bytes), so the precise
+//! message can be shown to the user:
+//!
+//! ```rust
+//! use steamidfx::id::{Id, Id32};
+//! use steamidfx::validate::{Field, Reason};
+//!
+//! let diagnostic = Id::validate("STEAM_0:2:11526534").unwrap_err();
+//! assert_eq!(diagnostic.field, Field::AuthenticationServer);
+//! assert_eq!(diagnostic.reason, Reason::OutOfRange { max: 1 });
+//! assert_eq!(diagnostic.position, 8..9);
+//! assert_eq!(
+//!     diagnostic.to_string(),
+//!     "Invalid authentication server at 8..9: the value is greater than 1."
+//! );
+//!
+//! let diagnostic = Id::validate("[X:1:23053068]").unwrap_err();
+//! assert_eq!(diagnostic.reason, Reason::UnknownLetter('X'));
+//! assert_eq!(diagnostic.position, 1..2);
+//!
+//! assert_eq!(
+//!     Id::validate("STEAM_0:0:11526534").unwrap(),
+//!     Id::Id32(Id32("STEAM_0:0:11526534".to_owned()))
+//! );
+//! ```
+//!
+//! The ids coming from the external services can be checked against valve's own
+//! validity rules (`CSteamID::IsValid`) with
+//! [`Id64::is_valid`](crate::id::Id64::is_valid), or converted to
+//! [`ValidId64`](crate::validate::ValidId64), which holds the valid ids only:
+//!
+//! ```rust
+//! use std::convert::TryFrom;
+//! use steamidfx::id::Id64;
+//! use steamidfx::validate::ValidId64;
+//!
+//! assert!(Id64(76561197983318796).is_valid());
+//! // An individual account with the account number `0`.
+//! assert!(!Id64(76561197960265728).is_valid());
+//! assert!(ValidId64::try_from(76561197960265728).is_err());
+//! ```
+use crate::id::{AccountType, Universe};
+use crate::id::{Id, Id3, Id32, Id64, MAX_ACCOUNT, MAX_INSTANCE};
+use alloc::borrow::ToOwned;
+use core::convert::TryFrom;
+use core::fmt;
+use core::ops::Range;
+
+/// The part of the steam id a diagnostic is about.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Hash, PartialEq, Eq)]
+pub enum Field {
+    /// The prefix, the separators and the square brackets of the format.
+    Format,
+    /// The whole steam id 64.
+    Id64,
+    /// The universe: the `X` in `STEAM_X:Y:Z`, or the number after the letter of the steam id 3.
+    Universe,
+    /// The account type: the letter of the steam id 3, or the bits of the steam id 64.
+    AccountType,
+    /// The authentication server bit: the `Y` in `STEAM_X:Y:Z`.
+    AuthenticationServer,
+    /// The account number: the `Z` in `STEAM_X:Y:Z`.
+    Account,
+    /// The account id of the steam id 3.
+    AccountId,
+    /// The instance of the steam id 3.
+    Instance,
+}
+
+/// The reason a field of the steam id is rejected.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum Reason {
+    /// The input is empty.
+    Empty,
+    /// A character which doesn't belong there.
+    UnexpectedCharacter {
+        /// The character found.
+        found: char,
+        /// What was expected instead.
+        expected: &'static str,
+    },
+    /// The input ends too early.
+    UnexpectedEnd {
+        /// What was expected instead.
+        expected: &'static str,
+    },
+    /// The letter of the steam id 3 doesn't represent an account type.
+    UnknownLetter(char),
+    /// The number doesn't represent a known account type.
+    UnknownAccountType(u64),
+    /// The number doesn't represent a known universe.
+    UnknownUniverse(u64),
+    /// The value stands for valve's invalid universe or account type.
+    Invalid,
+    /// The number is greater than the field can hold.
+    OutOfRange {
+        /// The greatest value of the field.
+        max: u64,
+    },
+}
+
+/// What is wrong with the steam id and where.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// The field which is wrong.
+    pub field: Field,
+    /// Why the field is wrong.
+    pub reason: Reason,
+    /// The range of bytes of the input the field occupies, empty when the field is missing.
+    pub position: Range<usize>,
+}
+
+impl Id {
+    /// Validates the steam id in any format, accepting the same ids as
+    /// [`ParseMode::Strict`](crate::parse::ParseMode::Strict) does.
+    ///
+    /// # Errors
+    /// Returns the diagnostic of the first field which is wrong.
+    pub fn validate(value: &str) -> Result<Id, Diagnostic> {
+        let mut validator = Validator { value, position: 0 };
+        match value.as_bytes().first() {
+            None => Err(Diagnostic {
+                field: Field::Format,
+                reason: Reason::Empty,
+                position: 0..0,
+            }),
+            Some(b'0'..=b'9') => validator.id64().map(Id::Id64),
+            Some(b'S') => validator.id32().map(|()| Id::Id32(Id32(value.to_owned()))),
+            Some(_) => validator.id3().map(|()| Id::Id3(Id3(value.to_owned()))),
+        }
+    }
+}
+
+/// Reads the steam id, remembering the position for the diagnostics.
+struct Validator<'a> {
+    value: &'a str,
+    position: usize,
+}
+
+impl Validator<'_> {
+    fn id64(&mut self) -> Result<Id64, Diagnostic> {
+        let (id, position) = self.number(Field::Id64, u64::MAX)?;
+        self.end()?;
+        check_universe(id >> 56, position.clone())?;
+        let account_type = id >> 52 & 0xF;
+        let reason = match AccountType::from_bits(account_type) {
+            None => Reason::UnknownAccountType(account_type),
+            Some(AccountType::Invalid) => Reason::Invalid,
+            Some(_) => return Ok(Id64(id)),
+        };
+        Err(diagnostic(Field::AccountType, reason, position))
+    }
+
+    fn id32(&mut self) -> Result<(), Diagnostic> {
+        for expected in "STEAM_".chars() {
+            self.separator(expected, "the \"STEAM_\" prefix")?;
+        }
+        self.universe()?;
+        self.separator(':', "':'")?;
+        self.digit(Field::AuthenticationServer, 1)?;
+        self.separator(':', "':'")?;
+        self.number(Field::Account, u64::from(MAX_ACCOUNT))?;
+        self.end()
+    }
+
+    fn id3(&mut self) -> Result<(), Diagnostic> {
+        let bracketed = self.eat('[');
+        self.letter()?;
+        self.separator(':', "':'")?;
+        let (universe, position) = self.universe()?;
+        check_universe(universe, position)?;
+        self.separator(':', "':'")?;
+        self.number(Field::AccountId, u64::from(u32::MAX))?;
+        if self.eat(':') {
+            self.number(Field::Instance, u64::from(MAX_INSTANCE))?;
+        }
+        if bracketed {
+            self.separator(']', "']'")?;
+        }
+        self.end()
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.value[self.position..].chars().next()
+    }
+
+    fn eat(&mut self, expected: char) -> bool {
+        let found = self.peek() == Some(expected);
+        if found {
+            self.position += expected.len_utf8();
+        }
+        found
+    }
+
+    fn separator(&mut self, separator: char, expected: &'static str) -> Result<(), Diagnostic> {
+        if self.eat(separator) {
+            return Ok(());
+        }
+        Err(self.unexpected(Field::Format, expected))
+    }
+
+    fn end(&self) -> Result<(), Diagnostic> {
+        match self.peek() {
+            None => Ok(()),
+            Some(_) => Err(self.unexpected(Field::Format, "the end")),
+        }
+    }
+
+    fn letter(&mut self) -> Result<(), Diagnostic> {
+        let letter = self
+            .peek()
+            .ok_or_else(|| self.unexpected(Field::AccountType, "the account type letter"))?;
+        let start = self.position;
+        self.position += letter.len_utf8();
+        let reason = match AccountType::from_letter(letter) {
+            None => Reason::UnknownLetter(letter),
+            Some(AccountType::Invalid) => Reason::Invalid,
+            Some(_) => return Ok(()),
+        };
+        Err(diagnostic(Field::AccountType, reason, start..self.position))
+    }
+
+    /// Reads the universe digit, which is `0` for the public universe in the steam id 32.
+    fn universe(&mut self) -> Result<(u64, Range<usize>), Diagnostic> {
+        let (universe, position) = self.digit(Field::Universe, 9)?;
+        if Universe::from_bits(universe).is_none() {
+            return Err(diagnostic(
+                Field::Universe,
+                Reason::UnknownUniverse(universe),
+                position,
+            ));
+        }
+        Ok((universe, position))
+    }
+
+    fn digit(&mut self, field: Field, max: u64) -> Result<(u64, Range<usize>), Diagnostic> {
+        let start = self.position;
+        let digit = match self.peek() {
+            Some(digit @ '0'..='9') => u64::from(digit as u8 - b'0'),
+            _ => return Err(self.unexpected(field, "a digit")),
+        };
+        self.position += 1;
+        if digit > max {
+            return Err(diagnostic(
+                field,
+                Reason::OutOfRange { max },
+                start..self.position,
+            ));
+        }
+        Ok((digit, start..self.position))
+    }
+
+    fn number(&mut self, field: Field, max: u64) -> Result<(u64, Range<usize>), Diagnostic> {
+        let start = self.position;
+        let digits = self.value[start..]
+            .bytes()
+            .take_while(u8::is_ascii_digit)
+            .count();
+        if digits == 0 {
+            return Err(self.unexpected(field, "a digit"));
+        }
+        self.position += digits;
+        match self.value[start..self.position].parse::<u64>() {
+            Ok(number) if number <= max => Ok((number, start..self.position)),
+            _ => Err(diagnostic(
+                field,
+                Reason::OutOfRange { max },
+                start..self.position,
+            )),
+        }
+    }
+
+    fn unexpected(&self, field: Field, expected: &'static str) -> Diagnostic {
+        match self.peek() {
+            Some(found) => diagnostic(
+                field,
+                Reason::UnexpectedCharacter { found, expected },
+                self.position..self.position + found.len_utf8(),
+            ),
+            None => diagnostic(
+                field,
+                Reason::UnexpectedEnd { expected },
+                self.position..self.position,
+            ),
+        }
+    }
+}
+
+/// Checks the universe is known and isn't the invalid one.
+fn check_universe(universe: u64, position: Range<usize>) -> Result<(), Diagnostic> {
+    let reason = match Universe::from_bits(universe) {
+        None => Reason::UnknownUniverse(universe),
+        Some(Universe::Invalid) => Reason::Invalid,
+        Some(_) => return Ok(()),
+    };
+    Err(diagnostic(Field::Universe, reason, position))
+}
+
+fn diagnostic(field: Field, reason: Reason, position: Range<usize>) -> Diagnostic {
+    Diagnostic {
+        field,
+        reason,
+        position,
+    }
+}
+
+impl fmt::Display for Field {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str(match self {
+            Field::Format => "format",
+            Field::Id64 => "steam id 64",
+            Field::Universe => "universe",
+            Field::AccountType => "account type",
+            Field::AuthenticationServer => "authentication server",
+            Field::Account => "account number",
+            Field::AccountId => "account id",
+            Field::Instance => "instance",
+        })
+    }
+}
+
+impl fmt::Display for Reason {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Reason::Empty => fmt.write_str("the steam id is empty"),
+            Reason::UnexpectedCharacter { found, expected } => {
+                write!(fmt, "unexpected {found:?}, expected {expected}")
+            }
+            Reason::UnexpectedEnd { expected } => {
+                write!(fmt, "unexpected end, expected {expected}")
+            }
+            Reason::UnknownLetter(letter) => write!(fmt, "unknown account type letter {letter:?}"),
+            Reason::UnknownAccountType(bits) => write!(fmt, "unknown account type {bits}"),
+            Reason::UnknownUniverse(universe) => write!(fmt, "unknown universe {universe}"),
+            Reason::Invalid => fmt.write_str("the value stands for an invalid id"),
+            Reason::OutOfRange { max } => write!(fmt, "the value is greater than {max}"),
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            fmt,
+            "Invalid {} at {}..{}: {}.",
+            self.field, self.position.start, self.position.end, self.reason
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Diagnostic {}
+
+/// The web instance, the greatest instance of the individual accounts.
+const WEB_INSTANCE: u32 = 4;
+
+/// The one of valve's validity rules (`CSteamID::IsValid`) a steam id 64 breaks.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum Rule {
+    /// The universe is known and is neither invalid nor the abandoned `Rc` one.
+    Universe,
+    /// The account type is known and isn't invalid.
+    AccountType,
+    /// The individual accounts, the clans and the game servers have a non-zero account id.
+    NonZeroAccountId,
+    /// The individual accounts have the instance up to the web one.
+    IndividualInstance,
+    /// The clans have the instance `0`.
+    ClanInstance,
+}
+
+impl Rule {
+    /// Returns the field the rule is about.
+    #[must_use]
+    pub const fn field(self) -> Field {
+        match self {
+            Rule::Universe => Field::Universe,
+            Rule::AccountType => Field::AccountType,
+            Rule::NonZeroAccountId => Field::AccountId,
+            Rule::IndividualInstance | Rule::ClanInstance => Field::Instance,
+        }
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str(match self {
+            Rule::Universe => "the universe must be known and neither invalid nor Rc",
+            Rule::AccountType => "the account type must be known and not invalid",
+            Rule::NonZeroAccountId => "the account id must not be 0 for this account type",
+            Rule::IndividualInstance => "the individual accounts must have the instance up to 4",
+            Rule::ClanInstance => "the clans must have the instance 0",
+        })
+    }
+}
+
+impl Id64 {
+    /// Returns `true` if the id follows valve's validity rules (`CSteamID::IsValid`): the
+    /// universe is known and is neither invalid nor the abandoned `Rc` one, the account type is
+    /// known and isn't invalid, the individual accounts, the clans and the game servers have a non-zero
+    /// account id, the individual accounts have the instance up to the web one and the clans
+    /// have the instance `0`.
+    #[must_use]
+    pub const fn is_valid(self) -> bool {
+        self.broken_rule().is_none()
+    }
+
+    /// Checks the id follows valve's validity rules, see [`Id64::is_valid`]. Unlike
+    /// [`Id::validate`], which diagnoses the text of a steam id, this checks the number itself.
+    ///
+    /// # Errors
+    /// Returns [`Error::BrokenRule`](crate::error::Error::BrokenRule) with the first rule the id
+    /// breaks.
+    pub fn validate(self) -> crate::error::Result<()> {
+        match self.broken_rule() {
+            Some((rule, value)) => Err(crate::error::Error::BrokenRule {
+                field: rule.field(),
+                value,
+                rule,
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns the first of valve's validity rules the id breaks, along with the value of the
+    /// field.
+    const fn broken_rule(self) -> Option<(Rule, u64)> {
+        match (self.universe(), self.account_type()) {
+            (Universe::Invalid | Universe::Rc | Universe::Unknown(_), _) => {
+                Some((Rule::Universe, self.0 >> 56))
+            }
+            (_, AccountType::Invalid | AccountType::Unknown(_)) => {
+                Some((Rule::AccountType, self.0 >> 52 & 0xF))
+            }
+            (_, account_type) => self.broken_account_rule(account_type),
+        }
+    }
+
+    /// Returns the first validity rule of the account type the id breaks.
+    const fn broken_account_rule(self, account_type: AccountType) -> Option<(Rule, u64)> {
+        let instance = self.instance() as u64;
+        match account_type {
+            AccountType::Individual | AccountType::Clan | AccountType::GameServer
+                if self.account_id().0 == 0 =>
+            {
+                Some((Rule::NonZeroAccountId, 0))
+            }
+            AccountType::Individual if instance > WEB_INSTANCE as u64 => {
+                Some((Rule::IndividualInstance, instance))
+            }
+            AccountType::Clan if instance != 0 => Some((Rule::ClanInstance, instance)),
+            _ => None,
+        }
+    }
+}
+
+/// A steam id 64 which follows valve's validity rules, see [`Id64::is_valid`]. The conversions
+/// into it reject the invalid ids, so the ids coming from the outside can be checked once.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Hash, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialization",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "u64", into = "u64")
+)]
+pub struct ValidId64(Id64);
+
+impl ValidId64 {
+    /// Returns the steam id 64.
+    #[must_use]
+    pub const fn id64(self) -> Id64 {
+        self.0
+    }
+}
+
+impl TryFrom<Id64> for ValidId64 {
+    type Error = crate::error::Error;
+
+    fn try_from(id: Id64) -> crate::error::Result<ValidId64> {
+        id.validate()?;
+        Ok(ValidId64(id))
+    }
+}
+
+impl TryFrom<u64> for ValidId64 {
+    type Error = crate::error::Error;
+
+    fn try_from(id: u64) -> crate::error::Result<ValidId64> {
+        ValidId64::try_from(Id64(id))
+    }
+}
+
+impl TryFrom<Id> for ValidId64 {
+    type Error = crate::error::Error;
+
+    fn try_from(id: Id) -> crate::error::Result<ValidId64> {
+        ValidId64::try_from(id.id64()?)
+    }
+}
+
+impl From<ValidId64> for Id64 {
+    fn from(id: ValidId64) -> Self {
+        id.0
+    }
+}
+
+impl From<ValidId64> for u64 {
+    fn from(id: ValidId64) -> Self {
+        id.0 .0
+    }
+}
+
+impl fmt::Display for ValidId64 {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, fmt)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn diagnose(value: &str) -> (Field, Reason, Range<usize>) {
+        let diagnostic = Id::validate(value).unwrap_err();
+        (diagnostic.field, diagnostic.reason, diagnostic.position)
+    }
+
+    #[allow(clippy::too_many_lines)]
+    #[test]
+    fn validate_reports_the_field() {
+        let expected = |found, expected| Reason::UnexpectedCharacter { found, expected };
+        let cases = [
+            ("", (Field::Format, Reason::Empty, 0..0)),
+            (
+                "STEAM_7:0:1",
+                (Field::Universe, Reason::UnknownUniverse(7), 6..7),
+            ),
+            (
+                "STEAM_0:2:1",
+                (
+                    Field::AuthenticationServer,
+                    Reason::OutOfRange { max: 1 },
+                    8..9,
+                ),
+            ),
+            (
+                "STEAM_0:1:4294967296",
+                (
+                    Field::Account,
+                    Reason::OutOfRange { max: 0x7FFF_FFFF },
+                    10..20,
+                ),
+            ),
+            (
+                "STEAM_0:1:",
+                (
+                    Field::Account,
+                    Reason::UnexpectedEnd {
+                        expected: "a digit",
+                    },
+                    10..10,
+                ),
+            ),
+            (
+                "Steam_0:1:5",
+                (Field::Format, expected('t', "the \"STEAM_\" prefix"), 1..2),
+            ),
+            (
+                "STEAM_0:1:5é",
+                (Field::Format, expected('é', "the end"), 11..13),
+            ),
+            (
+                "[X:1:5]",
+                (Field::AccountType, Reason::UnknownLetter('X'), 1..2),
+            ),
+            (
+                "ü:1:5",
+                (Field::AccountType, Reason::UnknownLetter('ü'), 0..2),
+            ),
+            ("U:6:5", (Field::Universe, Reason::UnknownUniverse(6), 2..3)),
+            (
+                "U:1:4294967296",
+                (
+                    Field::AccountId,
+                    Reason::OutOfRange { max: 0xFFFF_FFFF },
+                    4..14,
+                ),
+            ),
+            (
+                "U:1:5:1048576",
+                (Field::Instance, Reason::OutOfRange { max: 0xF_FFFF }, 6..13),
+            ),
+            (
+                "[U:1:5",
+                (
+                    Field::Format,
+                    Reason::UnexpectedEnd { expected: "']'" },
+                    6..6,
+                ),
+            ),
+            ("U:1:5]", (Field::Format, expected(']', "the end"), 5..6)),
+            ("U-1:5", (Field::Format, expected('-', "':'"), 1..2)),
+            (
+                "7656119798331879a",
+                (Field::Format, expected('a', "the end"), 16..17),
+            ),
+            (
+                "18446744073709551616",
+                (Field::Id64, Reason::OutOfRange { max: u64::MAX }, 0..20),
+            ),
+            (
+                "18446744073709551615",
+                (Field::Universe, Reason::UnknownUniverse(255), 0..20),
+            ),
+            (
+                "121597189939003397",
+                (Field::AccountType, Reason::UnknownAccountType(11), 0..18),
+            ),
+            ("0", (Field::Universe, Reason::Invalid, 0..1)),
+            (
+                "72057594037927941",
+                (Field::AccountType, Reason::Invalid, 0..17),
+            ),
+            ("[I:1:5]", (Field::AccountType, Reason::Invalid, 1..2)),
+            ("U:0:5", (Field::Universe, Reason::Invalid, 2..3)),
+        ];
+        for (value, diagnostic) in &cases {
+            assert_eq!(diagnose(value), *diagnostic, "{value}");
+        }
+    }
+
+    #[allow(clippy::unreadable_literal)]
+    #[allow(clippy::too_many_lines)]
+    #[test]
+    fn valve_validity_rules() {
+        let individual = Id64::builder().account(11526534);
+        let clan = Id64::builder().account_type(AccountType::Clan).account(2);
+        let game_server = Id64::builder().account_type(AccountType::GameServer);
+        let cases = [
+            (individual, None),
+            (individual.instance(4), None),
+            (individual.instance(7), Some((Rule::IndividualInstance, 7))),
+            (individual.account(0), Some((Rule::NonZeroAccountId, 0))),
+            (individual.universe(Universe::Rc), Some((Rule::Universe, 5))),
+            (
+                individual.universe(Universe::Invalid),
+                Some((Rule::Universe, 0)),
+            ),
+            (
+                individual.account_type(AccountType::Invalid),
+                Some((Rule::AccountType, 0)),
+            ),
+            (clan, None),
+            (clan.instance(1), Some((Rule::ClanInstance, 1))),
+            (clan.account(0), Some((Rule::NonZeroAccountId, 0))),
+            (game_server.account(1).instance(5), None),
+            (game_server, Some((Rule::NonZeroAccountId, 0))),
+            (
+                Id64::builder().account_type(AccountType::AnonymousGameServer),
+                None,
+            ),
+        ];
+        for (builder, broken_rule) in &cases {
+            let id = builder.build().unwrap();
+            assert_eq!(id.is_valid(), broken_rule.is_none(), "{id}");
+            let error = broken_rule.map(|(rule, value)| crate::error::Error::BrokenRule {
+                field: rule.field(),
+                value,
+                rule,
+            });
+            assert_eq!(id.validate().err(), error, "{id}");
+            assert_eq!(
+                ValidId64::try_from(id).ok().map(Id64::from),
+                Some(id).filter(|_| error.is_none())
+            );
+        }
+        assert!(!Id64(u64::MAX).is_valid());
+        let error = individual
+            .universe(Universe::Rc)
+            .build()
+            .unwrap()
+            .validate();
+        assert_eq!(
+            error.unwrap_err().to_string(),
+            "The universe 5 breaks valve's validity rules: \
+             the universe must be known and neither invalid nor Rc."
+        );
+    }
+
+    #[cfg(feature = "serialization")]
+    #[allow(clippy::unreadable_literal)]
+    #[test]
+    fn valid_id64_deserialization() {
+        let id: ValidId64 = serde_json::from_str("76561197983318796").unwrap();
+        assert_eq!(id.id64(), Id64(76561197983318796));
+        assert_eq!(serde_json::to_string(&id).unwrap(), "76561197983318796");
+        assert!(serde_json::from_str::<ValidId64>("76561197960265728").is_err());
+    }
+
+    #[allow(clippy::unreadable_literal)]
+    #[test]
+    fn validate_accepts_correct_ids() {
+        assert_eq!(
+            Id::validate("76561197983318796").unwrap(),
+            Id::Id64(Id64(76561197983318796))
+        );
+        for value in &[
+            "STEAM_0:1:11526534",
+            "[U:1:23053069]",
+            "g:1:4",
+            "[A:1:5:1048575]",
+        ] {
+            assert!(Id::validate(value).is_ok(), "{}", value);
+        }
+        let diagnostic = Id::validate("[U:1:5").unwrap_err();
+        assert_eq!(
+            diagnostic.to_string(),
+            "Invalid format at 6..6: unexpected end, expected ']'."
+        );
+    }
+}